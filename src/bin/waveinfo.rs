@@ -0,0 +1,429 @@
+use std::{collections::BTreeMap, io::Write, process::ExitCode};
+
+use serde::Serialize;
+use waveinfo::{ChunkDescriptor, Format, SpeakerPosition, WavFile};
+
+const USAGE: &str = "Usage: waveinfo [--json | --csv] [--strict] FILE...
+
+Print the audio details, INFO tags and chunks of wave files.
+
+Options:
+  --json      Print a JSON array with an object for each file
+  --csv       Print a CSV row for each file
+  --strict    Also exit with status 1 if any file is truncated
+  -h, --help  Print this message
+
+Exits with status 1 if any file can't be read as a wave file.";
+
+const CSV_HEADER: [&str; 11] = [
+    "file",
+    "format",
+    "duration",
+    "channels",
+    "layout",
+    "sample_rate",
+    "bit_depth",
+    "info",
+    "chunks",
+    "truncated",
+    "error",
+];
+
+#[derive(Debug, PartialEq)]
+enum OutputMode {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    mode: OutputMode,
+    strict: bool,
+    files: Vec<String>,
+}
+
+impl Options {
+    /// Parse command line arguments, returning None if usage should be shown instead
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut mode = OutputMode::Text;
+        let mut strict = false;
+        let mut files = Vec::new();
+        let mut only_files = false;
+
+        for arg in args {
+            match arg.as_str() {
+                _ if only_files => files.push(arg),
+                "--" => only_files = true,
+                "-h" | "--help" => return Ok(None),
+                "--json" | "--csv" if mode != OutputMode::Text => {
+                    Err("--json and --csv can't be used together")?
+                }
+                "--json" => mode = OutputMode::Json,
+                "--csv" => mode = OutputMode::Csv,
+                "--strict" => strict = true,
+                flag if flag.starts_with('-') && flag.len() > 1 => {
+                    Err(format!("Unknown option: {}", flag))?
+                }
+                _ => files.push(arg),
+            }
+        }
+
+        if files.is_empty() {
+            Err("No files given")?
+        }
+        Ok(Some(Self {
+            mode,
+            strict,
+            files,
+        }))
+    }
+}
+
+/// The details printed for a file
+#[derive(Debug, PartialEq, Serialize)]
+struct Summary {
+    file: String,
+    format: Format,
+    duration: f64,
+    channels: usize,
+    layout: Vec<SpeakerPosition>,
+    sample_rate: usize,
+    bit_depth: usize,
+    info: BTreeMap<String, String>,
+    chunks: Vec<ChunkDescriptor>,
+    truncated: bool,
+}
+
+impl Summary {
+    fn new(file: &str, wav: WavFile) -> Self {
+        let detail = wav.detail();
+        Self {
+            file: file.to_string(),
+            format: detail.format,
+            duration: detail.duration,
+            channels: detail.channels,
+            layout: detail.channel_positions,
+            sample_rate: detail.sample_rate,
+            bit_depth: detail.bit_depth,
+            // Unrecognised INFO codes are kept under their raw code
+            info: wav
+                .info_entries
+                .into_iter()
+                .map(|entry| (entry.label.unwrap_or(entry.id), entry.value))
+                .collect(),
+            chunks: wav.chunks,
+            truncated: wav.truncated,
+        }
+    }
+
+    fn layout_names(&self) -> Vec<String> {
+        self.layout
+            .iter()
+            .map(|position| format!("{:?}", position))
+            .collect()
+    }
+
+    fn write_text(&self, out: &mut impl Write) -> std::io::Result<()> {
+        writeln!(out, "{}", self.file)?;
+        writeln!(out, "  Format:      {}", self.format)?;
+        writeln!(out, "  Duration:    {:.3}s", self.duration)?;
+        writeln!(
+            out,
+            "  Channels:    {} ({})",
+            self.channels,
+            self.layout_names().join(", ")
+        )?;
+        writeln!(out, "  Sample rate: {} Hz", self.sample_rate)?;
+        writeln!(out, "  Bit depth:   {}", self.bit_depth)?;
+        if !self.info.is_empty() {
+            writeln!(out, "  INFO:")?;
+            for (label, value) in &self.info {
+                writeln!(out, "    {}: {}", label, value)?;
+            }
+        }
+        writeln!(out, "  Chunks:")?;
+        for chunk in &self.chunks {
+            write!(
+                out,
+                "    {}{:<4} at {}, {} bytes",
+                "  ".repeat(chunk.depth),
+                chunk.id,
+                chunk.offset,
+                chunk.actual_size
+            )?;
+            match chunk.truncated {
+                true => writeln!(out, " (truncated, {} declared)", chunk.declared_size)?,
+                false => writeln!(out)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn csv_row(&self) -> [String; 11] {
+        [
+            self.file.clone(),
+            self.format.to_string(),
+            self.duration.to_string(),
+            self.channels.to_string(),
+            self.layout_names().join(" "),
+            self.sample_rate.to_string(),
+            self.bit_depth.to_string(),
+            self.info
+                .iter()
+                .map(|(label, value)| format!("{}={}", label, value))
+                .collect::<Vec<String>>()
+                .join("; "),
+            // Codes are padded with spaces (eg. "fmt "), so they're trimmed and kept apart by ';'
+            self.chunks
+                .iter()
+                .map(|chunk| chunk.id.trim())
+                .collect::<Vec<&str>>()
+                .join(";"),
+            self.truncated.to_string(),
+            String::new(),
+        ]
+    }
+}
+
+/// The outcome of reading a single file
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+enum Report {
+    Loaded(Summary),
+    Failed { file: String, error: String },
+}
+
+impl Report {
+    fn new(file: &str) -> Self {
+        match WavFile::from_path(file) {
+            Ok(wav) => Self::Loaded(Summary::new(file, wav)),
+            Err(err) => Self::Failed {
+                file: file.to_string(),
+                error: err.to_string(),
+            },
+        }
+    }
+
+    fn csv_row(&self) -> [String; 11] {
+        match self {
+            Report::Loaded(summary) => summary.csv_row(),
+            Report::Failed { file, error } => {
+                let mut row: [String; 11] = Default::default();
+                row[0] = file.clone();
+                row[10] = error.clone();
+                row
+            }
+        }
+    }
+
+    /// Whether the file should give a failing exit status. Truncated files only fail when strict.
+    fn is_failure(&self, strict: bool) -> bool {
+        match self {
+            Report::Loaded(summary) => strict && summary.truncated,
+            Report::Failed { .. } => true,
+        }
+    }
+}
+
+/// Quote a CSV field where it contains a separator, quote or line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn write_csv_row(out: &mut impl Write, fields: &[impl AsRef<str>]) -> std::io::Result<()> {
+    let fields = fields
+        .iter()
+        .map(|field| csv_field(field.as_ref()))
+        .collect::<Vec<String>>();
+    writeln!(out, "{}", fields.join(","))
+}
+
+/// Write the reports in the given output mode. Errors are written to `err` in text mode.
+fn write_reports(
+    reports: &[Report],
+    mode: &OutputMode,
+    out: &mut impl Write,
+    err: &mut impl Write,
+) -> std::io::Result<()> {
+    match mode {
+        OutputMode::Text => {
+            let mut first = true;
+            for report in reports {
+                match report {
+                    Report::Loaded(summary) => {
+                        if !first {
+                            writeln!(out)?;
+                        }
+                        first = false;
+                        summary.write_text(out)?
+                    }
+                    Report::Failed { file, error } => writeln!(err, "{}: {}", file, error)?,
+                }
+            }
+        }
+        OutputMode::Json => {
+            serde_json::to_writer_pretty(&mut *out, reports)?;
+            writeln!(out)?;
+        }
+        OutputMode::Csv => {
+            write_csv_row(out, &CSV_HEADER)?;
+            for report in reports {
+                write_csv_row(out, &report.csv_row())?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let reports = options
+        .files
+        .iter()
+        .map(|file| Report::new(file))
+        .collect::<Vec<Report>>();
+
+    if let Err(error) = write_reports(
+        &reports,
+        &options.mode,
+        &mut std::io::stdout().lock(),
+        &mut std::io::stderr().lock(),
+    ) {
+        eprintln!("{}", error);
+        return ExitCode::FAILURE;
+    }
+
+    match reports
+        .iter()
+        .any(|report| report.is_failure(options.strict))
+    {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_options() {
+        assert_eq!(
+            Options::parse(args(&["--csv", "a.wav", "--strict", "--", "--json"])),
+            Ok(Some(Options {
+                mode: OutputMode::Csv,
+                strict: true,
+                files: args(&["a.wav", "--json"]),
+            }))
+        );
+        assert_eq!(Options::parse(args(&["a.wav", "--help"])), Ok(None));
+        assert!(Options::parse(args(&["--json", "--csv", "a.wav"])).is_err());
+        assert!(Options::parse(args(&["--xml", "a.wav"])).is_err());
+        assert!(Options::parse(args(&["--json"])).is_err());
+    }
+
+    #[test]
+    fn text_report() {
+        let reports = [
+            Report::new("tests/assets/arc_master.wav"),
+            Report::new("tests/assets/missing.wav"),
+        ];
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        write_reports(&reports, &OutputMode::Text, &mut out, &mut err).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "tests/assets/arc_master.wav
+  Format:      PCM
+  Duration:    0.200s
+  Channels:    2 (FRONT_LEFT, FRONT_RIGHT)
+  Sample rate: 192000 Hz
+  Bit depth:   16
+  Chunks:
+    RIFF at 0, 153768 bytes
+      fmt  at 12, 16 bytes
+      data at 36, 153732 bytes
+"
+        );
+        assert!(String::from_utf8(err)
+            .unwrap()
+            .starts_with("tests/assets/missing.wav: "));
+    }
+
+    #[test]
+    fn csv_report() {
+        let reports = [
+            Report::new("tests/assets/arc_master.wav"),
+            Report::Failed {
+                file: "bad.wav".to_string(),
+                error: "No fmt chunk found".to_string(),
+            },
+        ];
+        let mut out = Vec::new();
+        write_reports(&reports, &OutputMode::Csv, &mut out, &mut Vec::new()).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "file,format,duration,channels,layout,sample_rate,bit_depth,info,chunks,truncated,error
+tests/assets/arc_master.wav,PCM,0.200171875,2,FRONT_LEFT FRONT_RIGHT,192000,16,,RIFF;fmt;data,false,
+bad.wav,,,,,,,,,,No fmt chunk found
+"
+        );
+        assert_eq!(csv_field("a, \"b\""), "\"a, \"\"b\"\"\"");
+    }
+
+    #[test]
+    fn json_report() {
+        let reports = [Report::new("tests/assets/arc_master.wav")];
+        let mut out = Vec::new();
+        write_reports(&reports, &OutputMode::Json, &mut out, &mut Vec::new()).unwrap();
+
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(json[0]["format"], "PCM");
+        assert_eq!(
+            json[0]["layout"],
+            serde_json::json!(["FRONT_LEFT", "FRONT_RIGHT"])
+        );
+        assert_eq!(json[0]["chunks"][2]["id"], "data");
+        assert_eq!(json[0]["truncated"], false);
+    }
+
+    #[test]
+    fn truncated_report() {
+        // RIFF and data chunks declared longer than the file
+        let file_bytes = [
+            b"RIFF\x00\x01\x00\x00WAVE".as_slice(),
+            b"fmt \x10\x00\x00\x00\x01\x00\x02\x00\x44\xac\x00\x00\x10\xb1\x02\x00\x04\x00\x10\x00",
+            b"data\x80\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        ]
+        .concat();
+        let wav = WavFile::from_bytes(file_bytes).unwrap();
+        let report = Report::Loaded(Summary::new("cut.wav", wav));
+
+        assert_eq!(report.csv_row()[9], "true");
+        assert!(!report.is_failure(false));
+        assert!(report.is_failure(true));
+        assert!(!Report::new("tests/assets/arc_master.wav").is_failure(true));
+    }
+}
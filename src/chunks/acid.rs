@@ -0,0 +1,52 @@
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use super::Chunk;
+use crate::errors::ChunkError;
+
+const FLAG_ONE_SHOT: u32 = 0x01;
+const FLAG_ROOT_NOTE: u32 = 0x02;
+const FLAG_STRETCH: u32 = 0x04;
+const FLAG_DISK_BASED: u32 = 0x08;
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AcidInfo {
+    pub one_shot: bool,
+    pub root_note: Option<u16>,
+    pub stretch: bool,
+    pub disk_based: bool,
+    pub beats: u32,
+    pub meter_numerator: u16,
+    pub meter_denominator: u16,
+    pub tempo: f32,
+}
+
+impl TryFrom<Chunk> for AcidInfo {
+    type Error = ChunkError;
+
+    fn try_from(mut chunk: Chunk) -> Result<Self, Self::Error> {
+        chunk.validate_type("acid")?;
+
+        let flags = chunk.data_u32("dwFlags")?;
+        let root_note = chunk.data_u16("wRootNote")?;
+        // Undocumented fields
+        chunk.data_bytes::<6>("wReserved")?;
+        let beats = chunk.data_u32("dwBeats")?;
+        let meter_denominator = chunk.data_u16("wMeterDenominator")?;
+        let meter_numerator = chunk.data_u16("wMeterNumerator")?;
+        let tempo = chunk.data_f32("fTempo")?;
+
+        Ok(Self {
+            one_shot: flags & FLAG_ONE_SHOT != 0,
+            root_note: (flags & FLAG_ROOT_NOTE != 0).then_some(root_note),
+            stretch: flags & FLAG_STRETCH != 0,
+            disk_based: flags & FLAG_DISK_BASED != 0,
+            beats,
+            meter_numerator,
+            meter_denominator,
+            tempo,
+        })
+    }
+}
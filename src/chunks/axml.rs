@@ -0,0 +1,63 @@
+use std::io::Read;
+
+use bytes::Buf;
+use flate2::read::GzDecoder;
+
+use super::Chunk;
+use crate::errors::{ChunkError, ChunkParseError};
+
+const GZIP_MAGIC: &[u8] = b"\x1f\x8b";
+
+/// Largest XML document a bxml chunk is inflated to, so a small chunk can't exhaust memory
+const MAX_INFLATED_SIZE: u64 = 256 * 1024 * 1024;
+
+#[derive(Debug, PartialEq)]
+pub struct Axml {
+    pub xml: String,
+}
+
+impl TryFrom<Chunk> for Axml {
+    type Error = ChunkError;
+
+    fn try_from(mut chunk: Chunk) -> Result<Self, Self::Error> {
+        let xml = match chunk.id.as_str() {
+            "bxml" => {
+                // The compressed stream may be preceded by a format version
+                if !chunk.data.starts_with(GZIP_MAGIC) {
+                    let _version = chunk.data_u16("version")?;
+                }
+                let mut xml = inflate(&chunk, MAX_INFLATED_SIZE)?;
+                xml.truncate(xml.trim_end_matches('\0').len());
+                xml
+            }
+            _ => {
+                chunk.validate_type("axml")?;
+                chunk.data_text("XML")?
+            }
+        };
+
+        Ok(Self { xml })
+    }
+}
+
+/// Inflate the gzip stream at the cursor, failing if it expands beyond the limit
+pub(super) fn inflate(chunk: &Chunk, limit: u64) -> Result<String, ChunkParseError> {
+    let parse_error = |reason: String| ChunkParseError {
+        chunk_code: chunk.id.clone(),
+        reason,
+    };
+
+    // One byte past the limit is read, to tell a document at the limit from one beyond it
+    let mut xml = Vec::new();
+    GzDecoder::new(chunk.data.clone().reader())
+        .take(limit + 1)
+        .read_to_end(&mut xml)
+        .map_err(|err| parse_error(err.to_string()))?;
+    if xml.len() as u64 > limit {
+        Err(parse_error(format!(
+            "XML inflates to more than {} bytes",
+            limit
+        )))?
+    }
+    String::from_utf8(xml).map_err(|err| parse_error(err.to_string()))
+}
@@ -0,0 +1,94 @@
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use super::{cset::Charset, Chunk};
+use crate::errors::ChunkError;
+
+/// Value used by loudness fields that have not been set
+const LOUDNESS_UNSET: i16 = 0x7FFF;
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BextInfo {
+    pub description: String,
+    pub originator: String,
+    pub originator_reference: String,
+    pub origination_date: String,
+    pub origination_time: String,
+    pub time_reference: u64,
+    pub version: u16,
+    pub umid: Option<String>,
+    pub loudness_value: Option<f64>,
+    pub loudness_range: Option<f64>,
+    pub max_true_peak_level: Option<f64>,
+    pub max_momentary_loudness: Option<f64>,
+    pub max_short_term_loudness: Option<f64>,
+    pub coding_history: String,
+}
+
+impl BextInfo {
+    /// Parse a bext chunk, decoding its text with the file's charset
+    pub(crate) fn from_chunk(mut chunk: Chunk, charset: &Charset) -> Result<Self, ChunkError> {
+        chunk.validate_type("bext")?;
+
+        let description = chunk.data_padded_text(256, "Description", charset)?;
+        let originator = chunk.data_padded_text(32, "Originator", charset)?;
+        let originator_reference = chunk.data_padded_text(32, "OriginatorReference", charset)?;
+        let origination_date = chunk.data_padded_text(10, "OriginationDate", charset)?;
+        let origination_time = chunk.data_padded_text(8, "OriginationTime", charset)?;
+        let time_reference_low = chunk.data_u32("TimeReferenceLow")?;
+        let time_reference_high = chunk.data_u32("TimeReferenceHigh")?;
+        let version = chunk.data_u16("Version")?;
+        let umid = chunk.data_bytes::<64>("UMID")?;
+
+        let loudness_value = chunk.data_i16("LoudnessValue")?;
+        let loudness_range = chunk.data_i16("LoudnessRange")?;
+        let max_true_peak_level = chunk.data_i16("MaxTruePeakLevel")?;
+        let max_momentary_loudness = chunk.data_i16("MaxMomentaryLoudness")?;
+        let max_short_term_loudness = chunk.data_i16("MaxShortTermLoudness")?;
+        chunk.data_bytes::<180>("Reserved")?;
+        let coding_history = chunk.data_padded_text(chunk.data.len(), "CodingHistory", charset)?;
+
+        Ok(Self {
+            description,
+            originator,
+            originator_reference,
+            origination_date,
+            origination_time,
+            time_reference: u64::from(time_reference_high) << 32 | u64::from(time_reference_low),
+            version,
+            umid: parse_umid(version, umid),
+            loudness_value: parse_loudness(version, loudness_value),
+            loudness_range: parse_loudness(version, loudness_range),
+            max_true_peak_level: parse_loudness(version, max_true_peak_level),
+            max_momentary_loudness: parse_loudness(version, max_momentary_loudness),
+            max_short_term_loudness: parse_loudness(version, max_short_term_loudness),
+            coding_history,
+        })
+    }
+}
+
+/// Loudness fields are stored as 100 times their value, and were reserved before version 2
+fn parse_loudness(version: u16, value: i16) -> Option<f64> {
+    match version >= 2 && value != LOUDNESS_UNSET {
+        true => Some(f64::from(value) / 100.0),
+        false => None,
+    }
+}
+
+/// Hex representation of a basic (32 byte) or extended (64 byte) UMID
+fn parse_umid(version: u16, umid: [u8; 64]) -> Option<String> {
+    let (basic, extension) = umid.split_at(32);
+    let umid = match extension.iter().any(|b| *b != 0) {
+        true => umid.as_slice(),
+        false => basic,
+    };
+
+    // UMID was reserved before version 1
+    if version == 0 || umid.iter().all(|b| *b == 0) {
+        return None;
+    }
+
+    Some(umid.iter().map(|b| format!("{:02x}", b)).collect())
+}
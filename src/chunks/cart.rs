@@ -0,0 +1,143 @@
+#[cfg(feature = "python")]
+use pyo3::{prelude::*, types::PyDelta};
+
+use super::{cset::Charset, Chunk};
+use crate::errors::ChunkError;
+#[cfg(feature = "python")]
+use crate::util::seconds_to_timedelta;
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct Cart {
+    /// Parsed cart details, with timers to be added once the sample rate is known
+    pub info: CartInfo,
+    pub timers: Vec<(String, u32)>,
+}
+
+impl Cart {
+    /// Resolve timer sample positions into times
+    pub fn with_sample_rate(self, sample_rate: usize) -> CartInfo {
+        let timers = self
+            .timers
+            .into_iter()
+            .map(|(usage, sample_offset)| CartTimer {
+                usage,
+                sample_offset,
+                time: (sample_rate != 0).then(|| sample_offset as f64 / sample_rate as f64),
+            })
+            .collect();
+
+        CartInfo {
+            timers,
+            ..self.info
+        }
+    }
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CartInfo {
+    pub version: String,
+    pub title: String,
+    pub artist: String,
+    pub cut_id: String,
+    pub client_id: String,
+    pub category: String,
+    pub classification: String,
+    pub out_cue: String,
+    pub start_date: String,
+    pub start_time: String,
+    pub end_date: String,
+    pub end_time: String,
+    pub producer_app_id: String,
+    pub producer_app_version: String,
+    pub user_def: String,
+    pub level_reference: i32,
+    pub timers: Vec<CartTimer>,
+    pub url: String,
+    pub tag_text: String,
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CartTimer {
+    pub usage: String,
+    pub sample_offset: u32,
+    /// None if the file's sample rate is 0, as there's then no time to give
+    pub time: Option<f64>,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl CartTimer {
+    #[getter]
+    fn get_time<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyDelta>>> {
+        self.time
+            .map(|time| seconds_to_timedelta(py, time))
+            .transpose()
+    }
+}
+
+impl Cart {
+    /// Parse a cart chunk, decoding its text with the file's charset
+    pub(crate) fn from_chunk(mut chunk: Chunk, charset: &Charset) -> Result<Self, ChunkError> {
+        chunk.validate_type("cart")?;
+
+        let version = chunk.data_padded_text(4, "Version", charset)?;
+        let title = chunk.data_padded_text(64, "Title", charset)?;
+        let artist = chunk.data_padded_text(64, "Artist", charset)?;
+        let cut_id = chunk.data_padded_text(64, "CutID", charset)?;
+        let client_id = chunk.data_padded_text(64, "ClientID", charset)?;
+        let category = chunk.data_padded_text(64, "Category", charset)?;
+        let classification = chunk.data_padded_text(64, "Classification", charset)?;
+        let out_cue = chunk.data_padded_text(64, "OutCue", charset)?;
+        let start_date = chunk.data_padded_text(10, "StartDate", charset)?;
+        let start_time = chunk.data_padded_text(8, "StartTime", charset)?;
+        let end_date = chunk.data_padded_text(10, "EndDate", charset)?;
+        let end_time = chunk.data_padded_text(8, "EndTime", charset)?;
+        let producer_app_id = chunk.data_padded_text(64, "ProducerAppID", charset)?;
+        let producer_app_version = chunk.data_padded_text(64, "ProducerAppVersion", charset)?;
+        let user_def = chunk.data_padded_text(64, "UserDef", charset)?;
+        let level_reference = chunk.data_i32("dwLevelReference")?;
+
+        let mut timers = Vec::new();
+        for _ in 0..8 {
+            let usage = chunk.data_padded_text(4, "dwUsage", charset)?;
+            let value = chunk.data_u32("dwValue")?;
+            // Unused timers have no usage code
+            if !usage.is_empty() {
+                timers.push((usage, value));
+            }
+        }
+
+        chunk.data_bytes::<276>("Reserved")?;
+        let url = chunk.data_padded_text(1024, "URL", charset)?;
+        let tag_text = chunk.data_padded_text(chunk.data.len(), "TagText", charset)?;
+
+        Ok(Self {
+            info: CartInfo {
+                version,
+                title,
+                artist,
+                cut_id,
+                client_id,
+                category,
+                classification,
+                out_cue,
+                start_date,
+                start_time,
+                end_date,
+                end_time,
+                producer_app_id,
+                producer_app_version,
+                user_def,
+                level_reference,
+                timers: Vec::new(),
+                url,
+                tag_text,
+            },
+            timers,
+        })
+    }
+}
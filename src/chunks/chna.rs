@@ -0,0 +1,52 @@
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use super::Chunk;
+use crate::errors::ChunkError;
+
+#[derive(Debug, PartialEq)]
+pub struct Chna {
+    pub tracks: Vec<AdmTrack>,
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdmTrack {
+    pub track_index: u16,
+    pub uid: String,
+    pub track_format_ref: String,
+    pub pack_format_ref: String,
+}
+
+impl TryFrom<Chunk> for Chna {
+    type Error = ChunkError;
+
+    fn try_from(mut chunk: Chunk) -> Result<Self, Self::Error> {
+        chunk.validate_type("chna")?;
+
+        let _num_tracks = chunk.data_u16("numTracks")?;
+        let num_uids = chunk.data_u16("numUIDs")?;
+
+        let mut tracks = Vec::with_capacity(num_uids.into());
+        for _ in 0..num_uids {
+            let track_index = chunk.data_u16("trackIndex")?;
+            let uid = chunk.data_padded_string(12, "UID")?;
+            let track_format_ref = chunk.data_padded_string(14, "trackRef")?;
+            let pack_format_ref = chunk.data_padded_string(11, "packRef")?;
+            let _pad = chunk.data_bytes::<1>("pad")?;
+
+            // Unused entries are allowed for later use and have a zero track index
+            if track_index != 0 {
+                tracks.push(AdmTrack {
+                    track_index,
+                    uid,
+                    track_format_ref,
+                    pack_format_ref,
+                })
+            }
+        }
+
+        Ok(Self { tracks })
+    }
+}
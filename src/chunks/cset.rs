@@ -0,0 +1,88 @@
+use encoding_rs::{Encoding, UTF_8, WINDOWS_1252};
+
+use super::Chunk;
+use crate::errors::ChunkError;
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct Cset {
+    pub code_page: u16,
+    pub country_code: u16,
+    pub language: u16,
+    pub dialect: u16,
+}
+
+impl TryFrom<Chunk> for Cset {
+    type Error = ChunkError;
+
+    fn try_from(mut chunk: Chunk) -> Result<Self, Self::Error> {
+        chunk.validate_type("CSET")?;
+
+        Ok(Self {
+            code_page: chunk.data_u16("wCodePage")?,
+            country_code: chunk.data_u16("wCountryCode")?,
+            language: chunk.data_u16("wLanguageCode")?,
+            dialect: chunk.data_u16("wDialect")?,
+        })
+    }
+}
+
+/// Decides how text in a file is decoded
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Charset {
+    declared: Option<&'static Encoding>,
+    fallback: &'static Encoding,
+}
+
+impl Default for Charset {
+    fn default() -> Self {
+        Self {
+            declared: None,
+            fallback: WINDOWS_1252,
+        }
+    }
+}
+
+impl Charset {
+    /// Use a legacy encoding, given by its WHATWG label, for text that isn't valid UTF-8
+    pub(crate) fn with_fallback(label: &str) -> Option<Self> {
+        Encoding::for_label(label.as_bytes()).map(|fallback| Self {
+            declared: None,
+            fallback,
+        })
+    }
+
+    /// Use the code page declared by a CSET chunk, where it is one we can decode
+    pub(crate) fn with_cset(self, cset: &Cset) -> Self {
+        match codepage::to_encoding(cset.code_page) {
+            Some(declared) => Self {
+                declared: Some(declared),
+                ..self
+            },
+            None => self,
+        }
+    }
+
+    /// Decode text, returning it along with the name of the encoding used.
+    ///
+    /// A byte order mark takes precedence over a declared code page, which takes precedence
+    /// over UTF-8. Anything else is decoded with the fallback encoding.
+    pub(crate) fn decode(&self, bytes: &[u8]) -> (String, &'static str) {
+        let (encoding, text) = match Encoding::for_bom(bytes) {
+            Some((encoding, bom_length)) => (encoding, &bytes[bom_length..]),
+            None => match self.declared {
+                Some(encoding) => (encoding, bytes),
+                None => {
+                    // Without a declared code page, text ends at the first null byte
+                    let text = bytes.split(|x| *x == 0).next().unwrap_or_default();
+                    match std::str::from_utf8(text) {
+                        Ok(_) => (UTF_8, text),
+                        Err(_) => (self.fallback, text),
+                    }
+                }
+            },
+        };
+        let mut decoded = encoding.decode_without_bom_handling(text).0.into_owned();
+        decoded.truncate(decoded.find('\0').unwrap_or(decoded.len()));
+        (decoded, encoding.name())
+    }
+}
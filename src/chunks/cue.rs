@@ -0,0 +1,40 @@
+use super::Chunk;
+use crate::errors::ChunkError;
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct Cue {
+    pub points: Vec<CuePointEntry>,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct CuePointEntry {
+    pub id: u32,
+    pub position: u32,
+    pub data_chunk_id: String,
+    pub chunk_start: u32,
+    pub block_start: u32,
+    pub sample_offset: u32,
+}
+
+impl TryFrom<Chunk> for Cue {
+    type Error = ChunkError;
+
+    fn try_from(mut chunk: Chunk) -> Result<Self, Self::Error> {
+        chunk.validate_type("cue ")?;
+
+        let point_count = chunk.data_u32("dwCuePoints")?;
+        let mut points = Vec::new();
+        for _ in 0..point_count {
+            points.push(CuePointEntry {
+                id: chunk.data_u32("dwName")?,
+                position: chunk.data_u32("dwPosition")?,
+                data_chunk_id: chunk.data_string::<4>("fccChunk")?,
+                chunk_start: chunk.data_u32("dwChunkStart")?,
+                block_start: chunk.data_u32("dwBlockStart")?,
+                sample_offset: chunk.data_u32("dwSampleOffset")?,
+            });
+        }
+
+        Ok(Self { points })
+    }
+}
@@ -0,0 +1,300 @@
+use num_enum::TryFromPrimitive;
+#[cfg(feature = "python")]
+use pyo3::{prelude::*, types::PyBytes};
+
+use super::Chunk;
+use crate::errors::ChunkError;
+
+/// Sync word at the start of a Dolby Atmos supplemental segment
+const ATMOS_SUPPLEMENTAL_SYNC: u32 = 0xF8726FBD;
+/// Speaker configurations with a trim setting, in the order they are stored
+const TRIM_CONFIGURATIONS: [&str; 9] = [
+    "2.0", "5.1", "7.1", "2.0.2", "5.1.2", "7.1.2", "2.0.4", "5.1.4", "7.1.4",
+];
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DolbyMetadata {
+    pub version: String,
+    pub segments: Vec<DolbySegment>,
+    pub dolby_digital: Option<DolbyDigitalInfo>,
+    pub dolby_digital_plus: Option<DolbyDigitalInfo>,
+    pub atmos: Option<DolbyAtmosInfo>,
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DolbySegment {
+    pub segment_id: u8,
+    pub segment_type: DolbySegmentType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::util::hex_bytes"))]
+    pub data: Vec<u8>,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl DolbySegment {
+    #[getter]
+    fn get_data<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.data)
+    }
+}
+
+#[cfg_attr(feature = "python", pyclass(eq, eq_int, frozen, module = "waveinfo"))]
+#[derive(PartialEq, Clone, Copy, Debug, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+#[allow(clippy::upper_case_acronyms, non_camel_case_types)]
+pub enum DolbySegmentType {
+    DOLBY_E = 1,
+    DOLBY_DIGITAL = 3,
+    DOLBY_DIGITAL_PLUS = 7,
+    DOLBY_ATMOS = 9,
+    DOLBY_ATMOS_SUPPLEMENTAL = 10,
+    // Reserved or undocumented segment types
+    UNKNOWN = 0xFF,
+}
+
+impl DolbySegmentType {
+    pub fn from_code(segment_id: u8) -> Self {
+        Self::try_from(segment_id).unwrap_or(Self::UNKNOWN)
+    }
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DolbyDigitalInfo {
+    pub channel_mode: String,
+    pub lfe: bool,
+    pub dialnorm: i8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::util::optional_level"))]
+    pub center_mix_level: Option<f64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::util::optional_level"))]
+    pub surround_mix_level: Option<f64>,
+    pub preferred_downmix: Option<String>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::util::optional_level"))]
+    pub lt_rt_center_mix_level: Option<f64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::util::optional_level"))]
+    pub lt_rt_surround_mix_level: Option<f64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::util::optional_level"))]
+    pub lo_ro_center_mix_level: Option<f64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::util::optional_level"))]
+    pub lo_ro_surround_mix_level: Option<f64>,
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DolbyAtmosInfo {
+    pub creation_tool: Option<String>,
+    pub creation_tool_version: Option<String>,
+    pub trims: Vec<DolbyAtmosTrim>,
+    pub binaural_render_modes: Vec<BinauralRenderMode>,
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DolbyAtmosTrim {
+    pub configuration: String,
+    pub auto_trim: bool,
+    pub center_trim: f64,
+    pub surround_trim: f64,
+    pub height_trim: f64,
+}
+
+#[cfg_attr(feature = "python", pyclass(eq, eq_int, frozen, module = "waveinfo"))]
+#[derive(PartialEq, Clone, Copy, Debug, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+#[allow(clippy::upper_case_acronyms, non_camel_case_types)]
+pub enum BinauralRenderMode {
+    BYPASS = 0,
+    NEAR = 1,
+    FAR = 2,
+    MID = 3,
+    NOT_INDICATED = 4,
+    // Reserved render modes
+    UNKNOWN = 0xFF,
+}
+
+impl BinauralRenderMode {
+    pub fn from_code(mode: u8) -> Self {
+        Self::try_from(mode).unwrap_or(Self::UNKNOWN)
+    }
+}
+
+impl TryFrom<Chunk> for DolbyMetadata {
+    type Error = ChunkError;
+
+    fn try_from(mut chunk: Chunk) -> Result<Self, Self::Error> {
+        chunk.validate_type("dbmd")?;
+
+        let version = chunk
+            .data_u32("version")?
+            .to_be_bytes()
+            .map(|part| part.to_string())
+            .join(".");
+
+        let mut segments = Vec::new();
+        let mut dolby_digital = None;
+        let mut dolby_digital_plus = None;
+        let mut atmos: Option<DolbyAtmosInfo> = None;
+
+        loop {
+            let [segment_id] = chunk.data_bytes::<1>("metadata_segment_id")?;
+            if segment_id == 0 {
+                break;
+            }
+            let size = chunk.data_u16("metadata_segment_size")?.into();
+            chunk.validate_field_length(size, "metadata_segment_payload")?;
+            let mut payload = Chunk {
+                id: chunk.id.clone(),
+                size,
+                data: chunk.data.split_to(size),
+            };
+            // The checksum isn't verified, as encoders disagree on how it is calculated
+            chunk.data_bytes::<1>("metadata_segment_checksum")?;
+
+            let data = payload.data.to_vec();
+            let segment_type = DolbySegmentType::from_code(segment_id);
+            match segment_type {
+                DolbySegmentType::DOLBY_DIGITAL => {
+                    dolby_digital = Some(parse_dolby_digital(&mut payload, false)?)
+                }
+                DolbySegmentType::DOLBY_DIGITAL_PLUS => {
+                    dolby_digital_plus = Some(parse_dolby_digital(&mut payload, true)?)
+                }
+                DolbySegmentType::DOLBY_ATMOS => {
+                    let creation_tool = payload.data_padded_string(32, "content_creation_tool")?;
+                    let [major, minor, micro] = payload.data_bytes::<3>("tool_version")?;
+                    let atmos = atmos.get_or_insert_with(DolbyAtmosInfo::default);
+                    atmos.creation_tool = Some(creation_tool);
+                    atmos.creation_tool_version = Some(format!("{}.{}.{}", major, minor, micro));
+                }
+                DolbySegmentType::DOLBY_ATMOS_SUPPLEMENTAL => {
+                    let atmos = atmos.get_or_insert_with(DolbyAtmosInfo::default);
+                    parse_atmos_supplemental(&mut payload, atmos)?;
+                }
+                _ => (),
+            }
+
+            segments.push(DolbySegment {
+                segment_id,
+                segment_type,
+                data,
+            });
+        }
+
+        Ok(Self {
+            version,
+            segments,
+            dolby_digital,
+            dolby_digital_plus,
+            atmos,
+        })
+    }
+}
+
+/// Decode the program fields shared by Dolby Digital and Dolby Digital Plus segments
+fn parse_dolby_digital(
+    payload: &mut Chunk,
+    extended: bool,
+) -> Result<DolbyDigitalInfo, ChunkError> {
+    // Program ID, then data rate and bitstream mode
+    payload.data_bytes::<2>("program_info")?;
+    let [mode, levels] = payload.data_bytes::<2>("program_config")?;
+
+    let acmod = mode >> 5;
+    let mut info = DolbyDigitalInfo {
+        channel_mode: ["1+1", "1/0", "2/0", "3/0", "2/1", "3/1", "2/2", "3/2"][acmod as usize]
+            .to_string(),
+        lfe: levels & 0x80 != 0,
+        // A dialnorm of 0 is reserved, and treated as -31 dBFS
+        dialnorm: match levels & 0x1F {
+            0 => -31,
+            value => -(value as i8),
+        },
+        center_mix_level: [Some(-3.0), Some(-4.5), Some(-6.0), None][(mode >> 3 & 0x03) as usize],
+        surround_mix_level: [Some(-3.0), Some(-6.0), Some(f64::NEG_INFINITY), None]
+            [(mode >> 1 & 0x03) as usize],
+        preferred_downmix: None,
+        lt_rt_center_mix_level: None,
+        lt_rt_surround_mix_level: None,
+        lo_ro_center_mix_level: None,
+        lo_ro_surround_mix_level: None,
+    };
+
+    if extended {
+        let [lt_rt, lo_ro] = payload.data_bytes::<2>("downmix_config")?;
+        info.preferred_downmix = match lt_rt >> 6 {
+            1 => Some("Lt/Rt".to_string()),
+            2 => Some("Lo/Ro".to_string()),
+            3 => Some("Pro Logic II".to_string()),
+            _ => None,
+        };
+        info.lt_rt_center_mix_level = extended_center_mix_level(lt_rt >> 3);
+        info.lt_rt_surround_mix_level = extended_surround_mix_level(lt_rt);
+        info.lo_ro_center_mix_level = extended_center_mix_level(lo_ro >> 3);
+        info.lo_ro_surround_mix_level = extended_surround_mix_level(lo_ro);
+    }
+
+    Ok(info)
+}
+
+fn parse_atmos_supplemental(
+    payload: &mut Chunk,
+    atmos: &mut DolbyAtmosInfo,
+) -> Result<(), ChunkError> {
+    let sync = payload.data_u32("dasms_sync")?;
+    if sync != ATMOS_SUPPLEMENTAL_SYNC {
+        Err(payload.field_error(
+            "dasms_sync".to_string(),
+            format!("Invalid sync word: {:#x}", sync),
+        ))?
+    }
+    let object_count = payload.data_u16("object_count")?;
+    payload.data_bytes::<1>("reserved")?;
+
+    for configuration in TRIM_CONFIGURATIONS {
+        let [flags] = payload.data_bytes::<1>("auto_trim")?;
+        let [center, surround, height, ..] = payload.data_bytes::<14>("trim")?;
+        atmos.trims.push(DolbyAtmosTrim {
+            configuration: configuration.to_string(),
+            auto_trim: flags & 0x01 != 0,
+            center_trim: trim_level(center),
+            surround_trim: trim_level(surround),
+            height_trim: trim_level(height),
+        });
+    }
+
+    for _ in 0..object_count {
+        let [mode] = payload.data_bytes::<1>("binaural_render_mode")?;
+        atmos
+            .binaural_render_modes
+            .push(BinauralRenderMode::from_code(mode & 0x07));
+    }
+
+    Ok(())
+}
+
+/// Center mix level in dB from a 3-bit Lt/Rt or Lo/Ro code
+fn extended_center_mix_level(code: u8) -> Option<f64> {
+    Some([3.0, 1.5, 0.0, -1.5, -3.0, -4.5, -6.0, f64::NEG_INFINITY][(code & 0x07) as usize])
+}
+
+/// Surround mix level in dB from a 3-bit Lt/Rt or Lo/Ro code, the lowest three of which are reserved
+fn extended_surround_mix_level(code: u8) -> Option<f64> {
+    match code & 0x07 {
+        0..=2 => None,
+        code => Some([-1.5, -3.0, -4.5, -6.0, f64::NEG_INFINITY][(code - 3) as usize]),
+    }
+}
+
+/// Trims are stored as attenuation in half dB steps
+fn trim_level(code: u8) -> f64 {
+    -(code as f64) / 2.0
+}
@@ -0,0 +1,193 @@
+#[cfg(feature = "python")]
+use std::sync::Mutex;
+use std::{any::Any, collections::BTreeMap, fmt::Debug, sync::Arc};
+
+#[cfg(feature = "python")]
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes};
+
+use super::{Chunk, ChunkType};
+use crate::errors::ChunkError;
+
+/// Decodes chunks with a given code into a typed value.
+///
+/// Decoders are only used for chunks this crate doesn't otherwise understand. Values they return
+/// can be retrieved by type with [`crate::WavFile::decoded_value`].
+pub trait ChunkDecoder: Send + Sync + 'static {
+    type Output: Debug + PartialEq + Send + Sync + 'static;
+
+    /// Four character code of the chunks to decode
+    fn id(&self) -> &str;
+
+    fn decode(&self, chunk: Chunk) -> Result<Self::Output, ChunkError>;
+}
+
+/// The decoders to parse a file with, keyed by chunk code.
+/// Passed to [`crate::WavFile::from_bytes_with_decoders`].
+#[derive(Clone, Default)]
+pub struct ChunkDecoders(BTreeMap<String, Arc<dyn ErasedDecoder>>);
+
+impl ChunkDecoders {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a decoder, replacing any already added for the same chunk code
+    pub fn with<D: ChunkDecoder>(mut self, decoder: D) -> Self {
+        self.insert(decoder);
+        self
+    }
+
+    /// Add a decoder, replacing any already added for the same chunk code
+    pub fn insert<D: ChunkDecoder>(&mut self, decoder: D) {
+        self.0.insert(decoder.id().to_string(), Arc::new(decoder));
+    }
+
+    /// Remove the decoder for the given chunk code, if any
+    pub fn remove(&mut self, id: &str) {
+        self.0.remove(id);
+    }
+}
+
+impl Debug for ChunkDecoders {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.0.keys()).finish()
+    }
+}
+
+/// A decoder with its output type erased, so decoders of different types can share a registry
+trait ErasedDecoder: Send + Sync {
+    fn decode_value(&self, chunk: Chunk) -> Result<Box<dyn DecodedValue>, ChunkError>;
+}
+
+impl<D: ChunkDecoder> ErasedDecoder for D {
+    fn decode_value(&self, chunk: Chunk) -> Result<Box<dyn DecodedValue>, ChunkError> {
+        Ok(Box::new(self.decode(chunk)?))
+    }
+}
+
+/// The value returned by a Rust decoder
+pub(crate) trait DecodedValue: Any + Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn eq_value(&self, other: &dyn DecodedValue) -> bool;
+}
+
+impl<T: Any + Debug + PartialEq + Send + Sync> DecodedValue for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_value(&self, other: &dyn DecodedValue) -> bool {
+        other.as_any().downcast_ref::<T>() == Some(self)
+    }
+}
+
+impl PartialEq for dyn DecodedValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_value(other)
+    }
+}
+
+/// Python callables registered to decode chunks this crate doesn't understand, keyed by chunk code
+#[cfg(feature = "python")]
+static PY_DECODERS: Mutex<BTreeMap<String, Py<PyAny>>> = Mutex::new(BTreeMap::new());
+
+/// The value returned by a Python decoder
+#[cfg(feature = "python")]
+#[derive(Debug)]
+pub struct PyDecoded(pub Py<PyAny>);
+
+#[cfg(feature = "python")]
+impl PartialEq for PyDecoded {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.is(&other.0)
+    }
+}
+
+/// The value returned by a Python decoder, or the exception it raised
+#[cfg(feature = "python")]
+#[derive(Debug)]
+pub struct PyDecodeResult(pub PyResult<PyDecoded>);
+
+#[cfg(feature = "python")]
+impl PartialEq for PyDecodeResult {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Ok(value), Ok(other)) => value == other,
+            (Err(err), Err(other)) => Python::with_gil(|py| err.value(py).is(other.value(py))),
+            _ => false,
+        }
+    }
+}
+
+/// Register a callable to decode the payload of chunks with the given code
+#[cfg(feature = "python")]
+#[pyfunction]
+pub fn register_decoder(id: &str, decoder: Bound<'_, PyAny>) -> PyResult<()> {
+    if id.len() != 4 || !id.is_ascii() {
+        Err(PyValueError::new_err(format!(
+            "Invalid chunk code: {:?}",
+            id
+        )))?
+    }
+    if !decoder.is_callable() {
+        Err(PyValueError::new_err("Decoder must be callable"))?
+    }
+    PY_DECODERS
+        .lock()
+        .expect("Decoder registry poisoned")
+        .insert(id.to_string(), decoder.unbind());
+    Ok(())
+}
+
+/// Remove the decoder registered for the given chunk code, if any
+#[cfg(feature = "python")]
+#[pyfunction]
+pub fn unregister_decoder(id: &str) {
+    PY_DECODERS
+        .lock()
+        .expect("Decoder registry poisoned")
+        .remove(id);
+}
+
+impl Chunk {
+    /// Decode a chunk this crate doesn't understand with a decoder for its code, if there is one.
+    /// The given Rust decoders take precedence over registered Python ones.
+    pub(crate) fn decode_unknown(self, decoders: &ChunkDecoders) -> ChunkType {
+        if let Some(decoder) = decoders.0.get(&self.id) {
+            return ChunkType::Custom(self.id.clone(), decoder.decode_value(self));
+        }
+
+        #[cfg(feature = "python")]
+        if let Some(decoded) = self.py_decode() {
+            return ChunkType::Decoded(self.id, decoded);
+        }
+
+        ChunkType::Unknown(self)
+    }
+
+    /// Decode the chunk with a registered Python decoder, if there is one for its code
+    #[cfg(feature = "python")]
+    fn py_decode(&self) -> Option<PyDecodeResult> {
+        // Checked first so that files without registered decoders never need the GIL
+        if !PY_DECODERS
+            .lock()
+            .expect("Decoder registry poisoned")
+            .contains_key(&self.id)
+        {
+            return None;
+        }
+
+        Python::with_gil(|py| {
+            // The registry is unlocked before calling into Python, in case the decoder uses it
+            let decoder = PY_DECODERS
+                .lock()
+                .expect("Decoder registry poisoned")
+                .get(&self.id)?
+                .clone_ref(py);
+            let result = decoder
+                .call1(py, (PyBytes::new(py, &self.data),))
+                .map(PyDecoded);
+            Some(PyDecodeResult(result))
+        })
+    }
+}
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use super::Chunk;
+use crate::errors::ChunkError;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Ds64 {
+    pub riff_size: u64,
+    pub data_size: u64,
+    pub sample_count: u64,
+    pub table: HashMap<String, u64>,
+}
+
+impl Ds64 {
+    /// Real size of a chunk whose 32-bit size is set to the 0xFFFFFFFF placeholder
+    pub fn chunk_size(&self, chunk_code: &str) -> Option<u64> {
+        match chunk_code {
+            "RF64" | "BW64" => Some(self.riff_size),
+            "data" => Some(self.data_size),
+            other => self.table.get(other).copied(),
+        }
+    }
+}
+
+impl TryFrom<Chunk> for Ds64 {
+    type Error = ChunkError;
+
+    fn try_from(mut chunk: Chunk) -> Result<Self, Self::Error> {
+        chunk.validate_type("ds64")?;
+
+        let riff_size = chunk.data_u64("riffSize")?;
+        let data_size = chunk.data_u64("dataSize")?;
+        let sample_count = chunk.data_u64("sampleCount")?;
+        let table_length = chunk.data_u32("tableLength")?;
+
+        let mut table = HashMap::<String, u64>::new();
+        for _ in 0..table_length {
+            let chunk_code = chunk.data_string::<4>("chunkId")?;
+            let chunk_size = chunk.data_u64("chunkSize")?;
+            table.insert(chunk_code, chunk_size);
+        }
+
+        Ok(Self {
+            riff_size,
+            data_size,
+            sample_count,
+            table,
+        })
+    }
+}
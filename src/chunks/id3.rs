@@ -0,0 +1,289 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "python")]
+use pyo3::{prelude::*, types::PyBytes};
+
+use super::Chunk;
+use crate::errors::ChunkError;
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Id3Tag {
+    pub version: String,
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub track_number: Option<u32>,
+    pub track_total: Option<u32>,
+    pub isrc: Option<String>,
+    pub comments: Vec<Id3Comment>,
+    pub pictures: Vec<Id3Picture>,
+    pub text_frames: HashMap<String, String>,
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Id3Comment {
+    pub language: String,
+    pub description: String,
+    pub text: String,
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Id3Picture {
+    pub mime_type: String,
+    pub picture_type: u8,
+    pub description: String,
+    #[cfg_attr(feature = "serde", serde(with = "crate::util::hex_bytes"))]
+    pub data: Vec<u8>,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl Id3Picture {
+    #[getter]
+    fn get_data<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.data)
+    }
+}
+
+impl TryFrom<Chunk> for Id3Tag {
+    type Error = ChunkError;
+
+    fn try_from(mut chunk: Chunk) -> Result<Self, Self::Error> {
+        // Both upper and lower case chunk codes are used in the wild
+        if chunk.id != "ID3 " {
+            chunk.validate_type("id3 ")?;
+        }
+
+        if chunk.data_bytes::<3>("ID3")? != *b"ID3" {
+            Err(chunk.field_error("ID3".to_string(), "Not an ID3v2 tag".to_string()))?
+        }
+        let [major, revision] = chunk.data_bytes::<2>("version")?;
+        if !(3..=4).contains(&major) {
+            Err(chunk.field_error(
+                "version".to_string(),
+                format!("Unsupported ID3 version 2.{}", major),
+            ))?
+        }
+        let [flags] = chunk.data_bytes::<1>("flags")?;
+        let size = syncsafe(chunk.data_bytes::<4>("size")?) as usize;
+        chunk.validate_field_length(size, "frames")?;
+
+        let mut tag = chunk.data.split_to(size).to_vec();
+        if flags & 0x80 != 0 && major == 3 {
+            tag = unsynchronise(&tag);
+        }
+
+        let mut position = 0;
+        if flags & 0x40 != 0 {
+            // Extended header. Its size includes itself in v2.4, but not in v2.3
+            position = match (major, tag.first_chunk::<4>()) {
+                (3, Some(size)) => u32::from_be_bytes(*size) as usize + 4,
+                (_, Some(size)) => syncsafe(*size) as usize,
+                (_, None) => tag.len(),
+            };
+        }
+
+        let mut text_frames = HashMap::<String, String>::new();
+        let mut comments = Vec::new();
+        let mut pictures = Vec::new();
+
+        while let Some(header) = tag.get(position..position + 10) {
+            // Padding follows the last frame
+            if header[0] == 0 {
+                break;
+            }
+            let frame_id = String::from_utf8_lossy(&header[..4]).to_string();
+            let frame_size = match major {
+                3 => u32::from_be_bytes([header[4], header[5], header[6], header[7]]),
+                _ => syncsafe([header[4], header[5], header[6], header[7]]),
+            } as usize;
+            let format_flags = header[9];
+            let Some(frame) = tag.get(position + 10..position + 10 + frame_size) else {
+                break;
+            };
+            position += 10 + frame_size;
+
+            let Some(frame) = frame_content(major, format_flags, frame) else {
+                continue;
+            };
+            let Some((&encoding, body)) = frame.split_first() else {
+                continue;
+            };
+
+            match frame_id.as_str() {
+                "TXXX" => {
+                    let (description, value) = split_terminated(encoding, body);
+                    text_frames.insert(
+                        format!("TXXX:{}", decode_text(encoding, description)),
+                        decode_text_values(encoding, value),
+                    );
+                }
+                id if id.starts_with('T') => {
+                    text_frames.insert(frame_id, decode_text_values(encoding, body));
+                }
+                "COMM" => {
+                    let Some((language, body)) = body.split_first_chunk::<3>() else {
+                        continue;
+                    };
+                    let (description, text) = split_terminated(encoding, body);
+                    comments.push(Id3Comment {
+                        language: decode_text(0, language),
+                        description: decode_text(encoding, description),
+                        text: decode_text(encoding, trim_terminator(encoding, text)),
+                    });
+                }
+                "APIC" => {
+                    let (mime_type, body) = split_terminated(0, body);
+                    let Some((&picture_type, body)) = body.split_first() else {
+                        continue;
+                    };
+                    let (description, data) = split_terminated(encoding, body);
+                    pictures.push(Id3Picture {
+                        mime_type: decode_text(0, mime_type),
+                        picture_type,
+                        description: decode_text(encoding, description),
+                        data: data.to_vec(),
+                    });
+                }
+                _ => (),
+            }
+        }
+
+        let track = text_frames.get("TRCK").map(|track| {
+            let (number, total) = track.split_once('/').unwrap_or((track, ""));
+            (number.trim().parse().ok(), total.trim().parse().ok())
+        });
+
+        Ok(Self {
+            version: format!("2.{}.{}", major, revision),
+            title: text_frames.get("TIT2").cloned(),
+            artist: text_frames.get("TPE1").cloned(),
+            album: text_frames.get("TALB").cloned(),
+            track_number: track.and_then(|(number, _)| number),
+            track_total: track.and_then(|(_, total)| total),
+            isrc: text_frames.get("TSRC").cloned(),
+            comments,
+            pictures,
+            text_frames,
+        })
+    }
+}
+
+/// Decode a 28-bit integer stored in the low 7 bits of each byte
+fn syncsafe(bytes: [u8; 4]) -> u32 {
+    bytes
+        .iter()
+        .fold(0, |value, byte| (value << 7) | u32::from(byte & 0x7F))
+}
+
+/// Reverse unsynchronisation, which inserts a zero byte after every 0xFF
+fn unsynchronise(bytes: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut previous = 0;
+    for &byte in bytes {
+        if !(previous == 0xFF && byte == 0x00) {
+            output.push(byte);
+        }
+        previous = byte;
+    }
+    output
+}
+
+/// Frame content with any frame-level encoding removed, or None if it can't be read
+fn frame_content(major: u8, format_flags: u8, frame: &[u8]) -> Option<Vec<u8>> {
+    let (grouped, compressed, encrypted, unsynchronised, data_length) = match major {
+        3 => (
+            format_flags & 0x20 != 0,
+            format_flags & 0x80 != 0,
+            format_flags & 0x40 != 0,
+            false,
+            false,
+        ),
+        _ => (
+            format_flags & 0x40 != 0,
+            format_flags & 0x08 != 0,
+            format_flags & 0x04 != 0,
+            format_flags & 0x02 != 0,
+            format_flags & 0x01 != 0,
+        ),
+    };
+    if compressed || encrypted {
+        return None;
+    }
+
+    let skip = usize::from(grouped) + 4 * usize::from(data_length);
+    let frame = frame.get(skip..)?;
+    Some(match unsynchronised {
+        true => unsynchronise(frame),
+        false => frame.to_vec(),
+    })
+}
+
+fn is_wide(encoding: u8) -> bool {
+    matches!(encoding, 1 | 2)
+}
+
+/// Split a null-terminated string from the beginning of a frame
+fn split_terminated(encoding: u8, bytes: &[u8]) -> (&[u8], &[u8]) {
+    let terminator = match is_wide(encoding) {
+        true => bytes
+            .chunks_exact(2)
+            .position(|pair| pair == [0, 0])
+            .map(|index| (index * 2, 2)),
+        false => bytes
+            .iter()
+            .position(|byte| *byte == 0)
+            .map(|index| (index, 1)),
+    };
+    match terminator {
+        Some((index, length)) => (&bytes[..index], &bytes[index + length..]),
+        None => (bytes, &[]),
+    }
+}
+
+fn trim_terminator(encoding: u8, bytes: &[u8]) -> &[u8] {
+    split_terminated(encoding, bytes).0
+}
+
+/// Decode a text frame, which may hold several null-separated values in ID3v2.4
+fn decode_text_values(encoding: u8, mut bytes: &[u8]) -> String {
+    let mut values = Vec::new();
+    while !bytes.is_empty() {
+        let (value, rest) = split_terminated(encoding, bytes);
+        values.push(decode_text(encoding, value));
+        bytes = rest;
+    }
+    values.join("/")
+}
+
+/// Decode text in one of the ID3 text encodings
+fn decode_text(encoding: u8, bytes: &[u8]) -> String {
+    let utf16 = |bytes: &[u8], from_bytes: fn([u8; 2]) -> u16| {
+        let units = bytes
+            .chunks_exact(2)
+            .map(|pair| from_bytes([pair[0], pair[1]]))
+            .collect::<Vec<u16>>();
+        String::from_utf16_lossy(&units)
+    };
+
+    match encoding {
+        // ISO-8859-1
+        0 => bytes.iter().map(|byte| char::from(*byte)).collect(),
+        // UTF-16 with byte order mark
+        1 => match bytes {
+            [0xFE, 0xFF, rest @ ..] => utf16(rest, u16::from_be_bytes),
+            [0xFF, 0xFE, rest @ ..] => utf16(rest, u16::from_le_bytes),
+            _ => utf16(bytes, u16::from_le_bytes),
+        },
+        // UTF-16BE
+        2 => utf16(bytes, u16::from_be_bytes),
+        // UTF-8
+        _ => String::from_utf8_lossy(bytes).to_string(),
+    }
+}
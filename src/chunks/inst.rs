@@ -0,0 +1,39 @@
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use super::Chunk;
+use crate::errors::ChunkError;
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InstrumentInfo {
+    pub unshifted_note: u8,
+    pub fine_tune: i8,
+    pub gain: i8,
+    pub low_note: u8,
+    pub high_note: u8,
+    pub low_velocity: u8,
+    pub high_velocity: u8,
+}
+
+impl TryFrom<Chunk> for InstrumentInfo {
+    type Error = ChunkError;
+
+    fn try_from(mut chunk: Chunk) -> Result<Self, Self::Error> {
+        chunk.validate_type("inst")?;
+
+        let [unshifted_note, fine_tune, gain, low_note, high_note, low_velocity, high_velocity] =
+            chunk.data_bytes::<7>("bUnshiftedNote")?;
+
+        Ok(Self {
+            unshifted_note,
+            fine_tune: fine_tune as i8,
+            gain: gain as i8,
+            low_note,
+            high_note,
+            low_velocity,
+            high_velocity,
+        })
+    }
+}
@@ -0,0 +1,140 @@
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use roxmltree::{Document, Node};
+
+use super::Chunk;
+use crate::errors::{ChunkError, ChunkParseError};
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IxmlInfo {
+    pub version: Option<String>,
+    pub project: Option<String>,
+    pub scene: Option<String>,
+    pub take: Option<String>,
+    pub tape: Option<String>,
+    pub circled: Option<bool>,
+    pub note: Option<String>,
+    pub speed: Option<IxmlSpeed>,
+    pub tracks: Vec<IxmlTrack>,
+    pub xml: String,
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IxmlSpeed {
+    pub master_speed: Option<f64>,
+    pub current_speed: Option<f64>,
+    pub timecode_rate: Option<f64>,
+    pub drop_frame: Option<bool>,
+    pub file_sample_rate: Option<u32>,
+    pub audio_bit_depth: Option<u16>,
+    pub digitizer_sample_rate: Option<u32>,
+    pub timestamp_samples_since_midnight: Option<u64>,
+    pub timestamp_sample_rate: Option<u32>,
+    pub note: Option<String>,
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IxmlTrack {
+    pub channel_index: Option<u16>,
+    pub interleave_index: Option<u16>,
+    pub name: Option<String>,
+    pub function: Option<String>,
+}
+
+impl TryFrom<Chunk> for IxmlInfo {
+    type Error = ChunkError;
+
+    fn try_from(mut chunk: Chunk) -> Result<Self, Self::Error> {
+        chunk.validate_type("iXML")?;
+
+        let xml = chunk.data_text("BWFXML")?;
+        let document = Document::parse(&xml).map_err(|err| ChunkParseError {
+            chunk_code: chunk.id.clone(),
+            reason: err.to_string(),
+        })?;
+        let root = document.root_element();
+
+        let speed = child(root, "SPEED").map(|speed| IxmlSpeed {
+            master_speed: child_text(speed, "MASTER_SPEED").and_then(parse_rate),
+            current_speed: child_text(speed, "CURRENT_SPEED").and_then(parse_rate),
+            timecode_rate: child_text(speed, "TIMECODE_RATE").and_then(parse_rate),
+            drop_frame: child_text(speed, "TIMECODE_FLAG").map(|flag| flag == "DF"),
+            file_sample_rate: child_parsed(speed, "FILE_SAMPLE_RATE"),
+            audio_bit_depth: child_parsed(speed, "AUDIO_BIT_DEPTH"),
+            digitizer_sample_rate: child_parsed(speed, "DIGITIZER_SAMPLE_RATE"),
+            timestamp_samples_since_midnight: child_parsed::<u32>(
+                speed,
+                "TIMESTAMP_SAMPLES_SINCE_MIDNIGHT_LO",
+            )
+            .map(|low| {
+                let high = child_parsed::<u32>(speed, "TIMESTAMP_SAMPLES_SINCE_MIDNIGHT_HI");
+                u64::from(high.unwrap_or(0)) << 32 | u64::from(low)
+            }),
+            timestamp_sample_rate: child_parsed(speed, "TIMESTAMP_SAMPLE_RATE"),
+            note: child_text(speed, "NOTE"),
+        });
+
+        let tracks = child(root, "TRACK_LIST")
+            .map(|track_list| {
+                track_list
+                    .children()
+                    .filter(|node| node.has_tag_name("TRACK"))
+                    .map(|track| IxmlTrack {
+                        channel_index: child_parsed(track, "CHANNEL_INDEX"),
+                        interleave_index: child_parsed(track, "INTERLEAVE_INDEX"),
+                        name: child_text(track, "NAME"),
+                        function: child_text(track, "FUNCTION"),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Self {
+            version: child_text(root, "IXML_VERSION"),
+            project: child_text(root, "PROJECT"),
+            scene: child_text(root, "SCENE"),
+            take: child_text(root, "TAKE"),
+            tape: child_text(root, "TAPE"),
+            circled: child_text(root, "CIRCLED").map(|circled| circled == "TRUE"),
+            note: child_text(root, "NOTE"),
+            speed,
+            tracks,
+            xml,
+        })
+    }
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, tag_name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|child| child.has_tag_name(tag_name))
+}
+
+/// Trimmed text of a child element, if present and not empty
+fn child_text(node: Node, tag_name: &str) -> Option<String> {
+    child(node, tag_name)
+        .and_then(|child| child.text())
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+        .map(str::to_string)
+}
+
+fn child_parsed<T: std::str::FromStr>(node: Node, tag_name: &str) -> Option<T> {
+    child_text(node, tag_name).and_then(|text| text.parse().ok())
+}
+
+/// Parse a rate given either as a fraction (eg. "30000/1001") or a decimal
+fn parse_rate(rate: String) -> Option<f64> {
+    match rate.split_once('/') {
+        Some((numerator, denominator)) => {
+            let numerator = numerator.trim().parse::<f64>().ok()?;
+            let denominator = denominator.trim().parse::<f64>().ok()?;
+            (denominator != 0.0).then(|| numerator / denominator)
+        }
+        None => rate.parse().ok(),
+    }
+}
@@ -0,0 +1,120 @@
+use bytes::Buf;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use super::Chunk;
+use crate::errors::ChunkError;
+
+/// Size of the levl header, including the chunk code and size
+const HEADER_SIZE: usize = 128;
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeakEnvelope {
+    pub version: u32,
+    pub format: u32,
+    pub points_per_value: u32,
+    pub block_size: u32,
+    pub channels: u32,
+    pub frames: u32,
+    pub peak_of_peaks_position: Option<u32>,
+    pub timestamp: String,
+    pub positive_peaks: Vec<Vec<u16>>,
+    pub negative_peaks: Vec<Vec<u16>>,
+}
+
+impl TryFrom<Chunk> for PeakEnvelope {
+    type Error = ChunkError;
+
+    fn try_from(mut chunk: Chunk) -> Result<Self, Self::Error> {
+        chunk.validate_type("levl")?;
+
+        let version = chunk.data_u32("dwVersion")?;
+        let format = chunk.data_u32("dwFormat")?;
+        let points_per_value = chunk.data_u32("dwPointsPerValue")?;
+        let block_size = chunk.data_u32("dwBlockSize")?;
+        let channels = chunk.data_u32("dwPeakChannels")?;
+        let frames = chunk.data_u32("dwNumPeakFrames")?;
+        let peak_of_peaks_position = chunk.data_u32("dwPosPeakOfPeaks")?;
+        let offset_to_peaks = chunk.data_u32("dwOffsetToPeaks")? as usize;
+        let timestamp = chunk.data_padded_string(28, "strTimestamp")?;
+
+        // Offset is from the start of the chunk header
+        let header_remaining =
+            offset_to_peaks.max(HEADER_SIZE) - (chunk.size - chunk.data.len()) - 8;
+        chunk.validate_field_length(header_remaining, "reserved")?;
+        chunk.data.advance(header_remaining);
+
+        let value_size = match format {
+            1 => 1,
+            2 => 2,
+            other => Err(chunk.field_error(
+                "dwFormat".to_string(),
+                format!("Invalid peak format: {}", other),
+            ))?,
+        };
+        if !(1..=2).contains(&points_per_value) {
+            Err(chunk.field_error(
+                "dwPointsPerValue".to_string(),
+                format!("Invalid points per value: {}", points_per_value),
+            ))?
+        }
+        if channels == 0 {
+            Err(chunk.field_error("dwPeakChannels".to_string(), "No peak channels".to_string()))?
+        }
+        // Each channel takes at least a byte of the chunk, so this bounds the per-channel Vecs
+        // allocated below even when there are no frames to make peaks_size count them
+        if channels as usize > chunk.data.remaining() {
+            Err(chunk.field_error(
+                "dwPeakChannels".to_string(),
+                format!("{} peak channels don't fit in the chunk", channels),
+            ))?
+        }
+        // Checked against the chunk before allocating, so a corrupt header can't request more
+        // memory than the chunk could hold
+        let peaks_size = (frames as usize)
+            .checked_mul(channels as usize)
+            .and_then(|values| values.checked_mul(points_per_value as usize))
+            .and_then(|values| values.checked_mul(value_size))
+            .ok_or_else(|| {
+                chunk.field_error(
+                    "dwNumPeakFrames".to_string(),
+                    format!("Too many peak frames: {}", frames),
+                )
+            })?;
+        chunk.validate_field_length(peaks_size, "peaks")?;
+
+        let mut positive_peaks = vec![Vec::with_capacity(frames as usize); channels as usize];
+        let mut negative_peaks = match points_per_value {
+            2 => vec![Vec::with_capacity(frames as usize); channels as usize],
+            _ => Vec::new(),
+        };
+        let mut read_value = || match value_size {
+            1 => u16::from(chunk.data.get_u8()),
+            _ => chunk.data.get_u16_le(),
+        };
+        for _ in 0..frames {
+            for (channel, positive) in positive_peaks.iter_mut().enumerate() {
+                positive.push(read_value());
+                if let Some(negative) = negative_peaks.get_mut(channel) {
+                    negative.push(read_value());
+                }
+            }
+        }
+
+        Ok(Self {
+            version,
+            format,
+            points_per_value,
+            block_size,
+            channels,
+            frames,
+            peak_of_peaks_position: (peak_of_peaks_position != u32::MAX)
+                .then_some(peak_of_peaks_position),
+            timestamp,
+            positive_peaks,
+            negative_peaks,
+        })
+    }
+}
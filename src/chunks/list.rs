@@ -1,14 +1,16 @@
 use core::str;
-use std::{collections::HashMap, str::FromStr};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use std::str::FromStr;
 use strum::EnumMessage;
 use strum_macros::{EnumMessage, EnumString};
 
-use super::Chunk;
+use super::{cset::Charset, Chunk};
 use crate::errors::ChunkError;
 
 #[derive(Debug, PartialEq)]
 pub(crate) struct List {
-    list_type: String,
+    pub list_type: String,
     subchunks: Vec<Chunk>,
 }
 
@@ -28,22 +30,97 @@ impl TryFrom<Chunk> for List {
     }
 }
 
-impl TryInto<HashMap<String, String>> for List {
-    type Error = ();
+/// A single INFO list entry, kept with its raw code whether or not it is recognised
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InfoEntry {
+    pub id: String,
+    pub label: Option<String>,
+    pub value: String,
+    pub encoding: String,
+}
 
-    fn try_into(self) -> Result<HashMap<String, String>, Self::Error> {
+impl InfoEntry {
+    pub(crate) fn labelled_value(&self) -> Option<(String, String)> {
+        self.label
+            .as_ref()
+            .map(|label| (label.clone(), self.value.clone()))
+    }
+}
+
+impl List {
+    /// Decode the entries of an INFO list, in file order
+    pub(crate) fn info_entries(self, charset: &Charset) -> Result<Vec<InfoEntry>, ()> {
         match self.list_type.as_str() {
             "INFO" => Ok(self
                 .subchunks
                 .into_iter()
-                .flat_map(info_tuple)
-                .collect::<HashMap<String, String>>()),
-            // INFO seems to be the only defined list type?
+                .map(|chunk| info_entry(chunk, charset))
+                .collect::<Vec<InfoEntry>>()),
+            _ => Err(()),
+        }
+    }
+
+    /// Decode the entries of an associated data list, in file order
+    pub(crate) fn associated_data(self, charset: &Charset) -> Result<Vec<AssociatedData>, ()> {
+        match self.list_type.as_str() {
+            "adtl" => Ok(self
+                .subchunks
+                .into_iter()
+                .flat_map(|chunk| associated_data(chunk, charset))
+                .collect::<Vec<AssociatedData>>()),
             _ => Err(()),
         }
     }
 }
 
+/// Sub-chunks of an associated data list, each referencing a cue point ID
+#[derive(Debug, PartialEq)]
+pub(crate) enum AssociatedData {
+    Label {
+        cue_id: u32,
+        text: String,
+    },
+    Note {
+        cue_id: u32,
+        text: String,
+    },
+    LabelledText {
+        cue_id: u32,
+        sample_length: u32,
+        purpose: String,
+        text: String,
+    },
+}
+
+fn associated_data(mut chunk: Chunk, charset: &Charset) -> Option<AssociatedData> {
+    let cue_id = chunk.data_u32("dwName").ok()?;
+    match chunk.id.as_str() {
+        "labl" => Some(AssociatedData::Label {
+            cue_id,
+            text: charset.decode(&chunk.data).0,
+        }),
+        "note" => Some(AssociatedData::Note {
+            cue_id,
+            text: charset.decode(&chunk.data).0,
+        }),
+        "ltxt" => {
+            let sample_length = chunk.data_u32("dwSampleLength").ok()?;
+            let purpose = chunk.data_string::<4>("dwPurpose").ok()?;
+            // Country, language, dialect and code page
+            chunk.data_bytes::<8>("wCountry").ok()?;
+            Some(AssociatedData::LabelledText {
+                cue_id,
+                sample_length,
+                purpose,
+                text: charset.decode(&chunk.data).0,
+            })
+        }
+        _ => None,
+    }
+}
+
 #[derive(Debug, EnumMessage, EnumString, PartialEq)]
 #[allow(clippy::upper_case_acronyms, non_camel_case_types)]
 pub(crate) enum InfoChunk {
@@ -51,10 +128,38 @@ pub(crate) enum InfoChunk {
     IARL,
     #[strum(message = "Artist")]
     IART,
+    #[strum(message = "First Language")]
+    IAS1,
+    #[strum(message = "Second Language")]
+    IAS2,
+    #[strum(message = "Third Language")]
+    IAS3,
+    #[strum(message = "Fourth Language")]
+    IAS4,
+    #[strum(message = "Fifth Language")]
+    IAS5,
+    #[strum(message = "Sixth Language")]
+    IAS6,
+    #[strum(message = "Seventh Language")]
+    IAS7,
+    #[strum(message = "Eighth Language")]
+    IAS8,
+    #[strum(message = "Ninth Language")]
+    IAS9,
+    #[strum(message = "Base URL")]
+    IBSU,
+    #[strum(message = "Default Audio Stream")]
+    ICAS,
+    #[strum(message = "Costume Designer")]
+    ICDS,
     #[strum(message = "Commissioned")]
     ICMS,
     #[strum(message = "Comments")]
     ICMT,
+    #[strum(message = "Cinematographer")]
+    ICNM,
+    #[strum(message = "Country")]
+    ICNT,
     #[strum(message = "Copyright")]
     ICOP,
     #[strum(message = "Creation date")]
@@ -63,8 +168,16 @@ pub(crate) enum InfoChunk {
     ICRP,
     #[strum(message = "Dimensions")]
     IDIM,
+    #[strum(message = "Digitization Date")]
+    IDIT,
     #[strum(message = "Dots Per Inch")]
     IDPI,
+    #[strum(message = "Distributed By")]
+    IDST,
+    #[strum(message = "Edited By")]
+    IEDT,
+    #[strum(message = "Encoded By")]
+    IENC,
     #[strum(message = "Engineer")]
     IENG,
     #[strum(message = "Genre")]
@@ -73,39 +186,84 @@ pub(crate) enum InfoChunk {
     IKEY,
     #[strum(message = "Lightness")]
     ILGT,
+    #[strum(message = "Language")]
+    ILNG,
     #[strum(message = "Medium")]
     IMED,
+    #[strum(message = "Music By")]
+    IMUS,
     #[strum(message = "Name")]
     INAM,
+    #[strum(message = "Production Designer")]
+    IPDS,
     #[strum(message = "Palette Setting")]
     IPLT,
     #[strum(message = "Product")]
     IPRD,
+    #[strum(message = "Produced By")]
+    IPRO,
+    #[strum(message = "Part")]
+    IPRT,
+    #[strum(message = "Ripped By")]
+    IRIP,
+    #[strum(message = "Rating")]
+    IRTD,
     #[strum(message = "Subject")]
     ISBJ,
     #[strum(message = "Software")]
     ISFT,
+    #[strum(message = "Secondary Genre")]
+    ISGN,
     #[strum(message = "Sharpness")]
     ISHP,
+    #[strum(message = "SMPTE Time Code")]
+    ISMP,
     #[strum(message = "Source")]
     ISRC,
     #[strum(message = "Source Form")]
     ISRF,
+    #[strum(message = "Production Studio")]
+    ISTD,
+    #[strum(message = "Starring")]
+    ISTR,
     #[strum(message = "Technician")]
     ITCH,
+    #[strum(message = "Track Number")]
+    ITRK,
+    #[strum(message = "Written By")]
+    IWRI,
+    #[strum(message = "Part Number")]
+    PRT1,
+    #[strum(message = "Total Parts")]
+    PRT2,
+    #[strum(message = "End Time Code")]
+    TCDO,
+    #[strum(message = "Start Time Code")]
+    TCOD,
+    #[strum(message = "Length")]
+    TLEN,
+    #[strum(message = "Organization")]
+    TORG,
+    #[strum(message = "URL")]
+    TURL,
+    #[strum(message = "Version")]
+    TVER,
+    #[strum(message = "Year")]
+    YEAR,
 }
 
-fn info_tuple(mut chunk: Chunk) -> Option<(String, String)> {
-    if let Ok(infochunk) = InfoChunk::from_str(&chunk.id) {
-        if let Ok(value) = chunk.data_zstring("Info value") {
-            return Some((
-                infochunk
-                    .get_message()
-                    .expect("Info type without label")
-                    .to_string(),
-                value,
-            ));
-        }
-    };
-    None
+fn info_entry(chunk: Chunk, charset: &Charset) -> InfoEntry {
+    let label = InfoChunk::from_str(&chunk.id).ok().map(|infochunk| {
+        infochunk
+            .get_message()
+            .expect("Info type without label")
+            .to_string()
+    });
+    let (value, encoding) = charset.decode(&chunk.data);
+    InfoEntry {
+        id: chunk.id,
+        label,
+        value,
+        encoding: encoding.to_string(),
+    }
 }
@@ -0,0 +1,131 @@
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use super::ds64::Ds64;
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChunkDescriptor {
+    pub id: String,
+    pub offset: usize,
+    pub declared_size: u32,
+    pub actual_size: usize,
+    pub padding: Option<u8>,
+    pub parent: Option<String>,
+    pub depth: usize,
+    pub truncated: bool,
+}
+
+/// Describe the root chunk of a file and every chunk inside it. Unlike iterating chunks, this
+/// tolerates damage and keeps going as far as the chunk headers and the file allow.
+pub(crate) fn chunk_map(data: &[u8], ds64: Option<&Ds64>) -> Vec<ChunkDescriptor> {
+    let mut descriptors = Vec::new();
+    let Some(root) = describe(data, 0, data.len(), ds64, None, 0) else {
+        return descriptors;
+    };
+    // The RIFF header and form type take up the first 12 bytes
+    let form_type = data
+        .get(8..12)
+        .map(|form_type| String::from_utf8_lossy(form_type).to_string());
+    let end = root.offset + 8 + root.actual_size;
+    descriptors.push(root);
+
+    // An explicit stack, rather than recursion, so deeply nested lists can't overflow the stack
+    let mut stack = vec![Level {
+        position: 12,
+        end,
+        parent: form_type,
+        depth: 1,
+    }];
+    while let Some(level) = stack.last_mut() {
+        let Some(descriptor) = describe(
+            data,
+            level.position,
+            level.end,
+            ds64,
+            level.parent.clone(),
+            level.depth,
+        ) else {
+            stack.pop();
+            continue;
+        };
+
+        let data_start = descriptor.offset + 8;
+        let data_end = data_start + descriptor.actual_size;
+        level.position = match descriptor.truncated {
+            // Nothing after a truncated chunk can be found
+            true => level.end,
+            false => (data_end + (data_end - data_start) % 2).min(level.end),
+        };
+
+        let child = match descriptor.id == "LIST" && descriptor.actual_size >= 4 {
+            true => Some(Level {
+                position: data_start + 4,
+                end: data_end,
+                parent: Some(
+                    String::from_utf8_lossy(&data[data_start..data_start + 4]).to_string(),
+                ),
+                depth: level.depth + 1,
+            }),
+            false => None,
+        };
+        descriptors.push(descriptor);
+        stack.extend(child);
+    }
+
+    descriptors
+}
+
+/// Chunks still to be described within a list-like chunk
+struct Level {
+    position: usize,
+    end: usize,
+    parent: Option<String>,
+    depth: usize,
+}
+
+/// Describe the chunk whose header starts at `position`, if there is a readable header before `end`
+fn describe(
+    data: &[u8],
+    position: usize,
+    end: usize,
+    ds64: Option<&Ds64>,
+    parent: Option<String>,
+    depth: usize,
+) -> Option<ChunkDescriptor> {
+    let header = data
+        .get(position..position + 8)
+        .filter(|_| position + 8 <= end)?;
+    // Without a readable code, there's no telling whether this is a chunk at all
+    let id = std::str::from_utf8(&header[..4])
+        .ok()
+        .filter(|id| id.is_ascii())?;
+    let declared_size = u32::from_le_bytes(header[4..].try_into().unwrap());
+    let size = match (declared_size, ds64) {
+        (u32::MAX, Some(ds64)) => ds64
+            .chunk_size(id)
+            .and_then(|size| usize::try_from(size).ok())
+            .unwrap_or(u32::MAX as usize),
+        (size, _) => size as usize,
+    };
+
+    let data_start = position + 8;
+    let actual_size = size.min(end - data_start);
+    let data_end = data_start + actual_size;
+    let padding = match size & 1 {
+        1 if actual_size == size && data_end < end => Some(data[data_end]),
+        _ => None,
+    };
+
+    Some(ChunkDescriptor {
+        id: id.to_string(),
+        offset: position,
+        declared_size,
+        actual_size,
+        padding,
+        parent,
+        depth,
+        truncated: actual_size < size,
+    })
+}
@@ -4,12 +4,30 @@ use crate::errors::{
 use ascii::AsciiString;
 use bytes::{Buf, Bytes};
 
+pub mod acid;
+pub mod axml;
+pub mod bext;
+pub mod cart;
+pub mod chna;
+pub mod cset;
+pub mod cue;
+pub mod dbmd;
+pub mod decoders;
+pub mod ds64;
 pub mod fact;
 pub mod fmt;
+pub mod id3;
+pub mod inst;
+pub mod ixml;
+pub mod levl;
 pub mod list;
+pub mod map;
+pub mod plst;
+pub mod smpl;
+pub mod xmp;
 
 #[derive(Debug, PartialEq)]
-pub(crate) struct Chunk {
+pub struct Chunk {
     pub id: String,
     pub size: usize,
     pub data: Bytes,
@@ -27,14 +45,36 @@ impl Chunk {
     }
 
     /// Raise a fatal field error referencing the current chunk and cursor position
-    pub fn fatal_field_error(&self, field_name: &str, reason: String) -> FatalError {
-        FatalError {
-            inner: self.field_error(field_name.to_string(), reason).into(),
-        }
+    pub(crate) fn fatal_field_error(&self, field_name: &str, reason: String) -> FatalError {
+        self.field_error(field_name.to_string(), reason).into()
     }
 
     /// Pop a chunk from the beginning of a Bytes
-    pub fn pop_from_data(chunk_data: &mut Bytes) -> Result<Self, ChunkError> {
+    pub(crate) fn pop_from_data(chunk_data: &mut Bytes) -> Result<Self, ChunkError> {
+        Self::pop_from_data_sized(chunk_data, None)
+    }
+
+    /// Pop a chunk from the beginning of a Bytes, resolving placeholder sizes from a ds64 chunk
+    pub(crate) fn pop_from_data_sized(
+        chunk_data: &mut Bytes,
+        ds64: Option<&ds64::Ds64>,
+    ) -> Result<Self, ChunkError> {
+        Self::pop(chunk_data, ds64, false)
+    }
+
+    /// Pop a chunk that may claim to run past the end of a Bytes, keeping as much as there is
+    pub(crate) fn pop_truncated(
+        chunk_data: &mut Bytes,
+        ds64: Option<&ds64::Ds64>,
+    ) -> Result<Self, ChunkError> {
+        Self::pop(chunk_data, ds64, true)
+    }
+
+    fn pop(
+        chunk_data: &mut Bytes,
+        ds64: Option<&ds64::Ds64>,
+        truncate: bool,
+    ) -> Result<Self, ChunkError> {
         if chunk_data.len() < 8 {
             Err(ChunkParseError::new_idless(
                 "Invalid chunk: too short".to_string(),
@@ -45,18 +85,26 @@ impl Chunk {
             .map_err(|err| ChunkParseError::new_idless(format!("Invalid chunk code: {}", err)))?
             .to_string();
 
-        let size = chunk_data.get_u32_le().try_into()?;
-        if size > chunk_data.len() {
-            Err(ChunkParseError {
+        let size: usize = match (chunk_data.get_u32_le(), ds64) {
+            (u32::MAX, Some(ds64)) => ds64.chunk_size(&id).unwrap_or(u32::MAX.into()),
+            (size, _) => size.into(),
+        }
+        .try_into()?;
+        let size = match size > chunk_data.len() {
+            // Truncated deliveries usually lose the end of the audio, so keep what there is of it
+            true if truncate || id == "data" => chunk_data.len(),
+            true => Err(ChunkParseError {
                 chunk_code: id.clone(),
                 reason: "Requested chunk size too large".to_string(),
-            })?
-        }
+            })?,
+            false => size,
+        };
 
         let data = chunk_data.split_to(size);
 
-        // Chunks are always 2-byte aligned with a possible padding byte
-        if (size & 1) == 1 {
+        // Chunks are always 2-byte aligned with a possible padding byte, which writers often leave
+        // off the last chunk
+        if (size & 1) == 1 && !chunk_data.is_empty() {
             chunk_data.advance(1)
         }
 
@@ -76,12 +124,30 @@ impl Chunk {
     }
 
     /// Turn this into a typed chunk
-    pub fn load_type(self) -> Result<ChunkType, ChunkError> {
+    pub(crate) fn load_type(self) -> Result<ChunkType, ChunkError> {
         Ok(match self.id.as_str() {
+            "ds64" => ChunkType::Ds64(self.try_into()?),
             "fmt " => ChunkType::Fmt(self.try_into()?),
+            // Decoded once the whole file is read, as their text depends on the file's charset
+            "bext" => ChunkType::Bext(self),
+            "iXML" => ChunkType::Ixml(self.try_into()?),
             "fact" => ChunkType::Fact(self.try_into()?),
             "data" => ChunkType::Data(self),
             "LIST" => ChunkType::List(self.try_into()?),
+            "cue " => ChunkType::Cue(self.try_into()?),
+            "plst" => ChunkType::Plst(self.try_into()?),
+            "smpl" => ChunkType::Smpl(self.try_into()?),
+            "inst" => ChunkType::Inst(self.try_into()?),
+            "acid" => ChunkType::Acid(self.try_into()?),
+            "cart" => ChunkType::Cart(self),
+            "id3 " | "ID3 " => ChunkType::Id3(self.try_into()?),
+            "_PMX" => ChunkType::Xmp(self.try_into()?),
+            "levl" => ChunkType::Levl(self.try_into()?),
+            "chna" => ChunkType::Chna(self.try_into()?),
+            "axml" | "bxml" => ChunkType::Axml(self.try_into()?),
+            "dbmd" => ChunkType::Dbmd(self.try_into()?),
+            "CSET" => ChunkType::Cset(self.try_into()?),
+            "JUNK" | "junk" | "PAD " | "FLLR" => ChunkType::Filler(self),
             _ => ChunkType::Unknown(self),
         })
     }
@@ -136,34 +202,54 @@ impl Chunk {
         }
     }
 
-    /// Pop a null-terminated string from beginning of a chunk
-    pub fn data_zstring(&mut self, field_name: &str) -> Result<String, FieldParseError> {
-        let str_bytes = self
-            .data
-            .clone()
-            .into_iter()
-            .take_while(|x| *x != 0)
-            .collect::<Vec<u8>>();
-        if str_bytes.len() >= self.data.remaining() {
-            return Err(FieldParseError {
+    /// Pop a null-padded string of given length from beginning of a chunk
+    pub fn data_padded_string(
+        &mut self,
+        len: usize,
+        field_name: &str,
+    ) -> Result<String, FieldParseError> {
+        self.validate_field_length(len, field_name)?;
+        let position = self.size - self.data.len();
+        let str_bytes = self.data.split_to(len);
+        let str_bytes = str_bytes.split(|x| *x == 0).next().unwrap_or_default();
+
+        AsciiString::from_ascii(str_bytes)
+            .map(|s| s.to_string())
+            .map_err(|err| FieldParseError {
                 chunk_code: self.id.to_owned(),
                 field_name: field_name.to_string(),
-                position: self.size - self.data.len(),
-                reason: "String not null terminated".to_string(),
-            });
-        };
-        let str_res = std::str::from_utf8(&str_bytes)
+                position,
+                reason: err.to_string(),
+            })
+    }
+
+    /// Pop a null-padded text field of given length. Fields specified as ASCII are often written
+    /// in a local code page, so they are decoded with the file's charset rather than failing.
+    pub(crate) fn data_padded_text(
+        &mut self,
+        len: usize,
+        field_name: &str,
+        charset: &cset::Charset,
+    ) -> Result<String, FieldParseError> {
+        self.validate_field_length(len, field_name)?;
+        let text_bytes = self.data.split_to(len);
+        Ok(charset.decode(&text_bytes).0)
+    }
+
+    /// Pop the remainder of a chunk as UTF-8 text, ignoring any null padding
+    pub fn data_text(&mut self, field_name: &str) -> Result<String, FieldParseError> {
+        let position = self.size - self.data.len();
+        let text_bytes = self.data.split_to(self.data.len());
+        let text_len = text_bytes.len() - text_bytes.iter().rev().take_while(|x| **x == 0).count();
+
+        std::str::from_utf8(&text_bytes[..text_len])
             .map(|s| s.to_string())
             .map_err(|e| FieldParseError {
                 chunk_code: self.id.to_owned(),
                 field_name: field_name.to_string(),
-                position: self.size - self.data.len(),
+                position,
                 reason: e.to_string(),
-            });
-        if str_res.is_ok() {
-            self.data.advance(str_bytes.len() + 1)
-        };
-        str_res
+            })
     }
 
     pub fn data_u16(&mut self, field_name: &str) -> Result<u16, FieldParseError> {
@@ -171,27 +257,49 @@ impl Chunk {
         Ok(self.data.get_u16_le())
     }
 
+    pub fn data_i16(&mut self, field_name: &str) -> Result<i16, FieldParseError> {
+        self.validate_field_length(2, field_name)?;
+        Ok(self.data.get_i16_le())
+    }
+
+    pub fn data_i32(&mut self, field_name: &str) -> Result<i32, FieldParseError> {
+        self.validate_field_length(4, field_name)?;
+        Ok(self.data.get_i32_le())
+    }
+
     pub fn data_u32(&mut self, field_name: &str) -> Result<u32, FieldParseError> {
         self.validate_field_length(4, field_name)?;
         Ok(self.data.get_u32_le())
     }
 
-    pub fn typed_iter(self) -> ChunkTypeIter {
-        ChunkTypeIter { chunk: self }
+    pub fn data_f32(&mut self, field_name: &str) -> Result<f32, FieldParseError> {
+        self.validate_field_length(4, field_name)?;
+        Ok(self.data.get_f32_le())
     }
-}
 
-impl Iterator for Chunk {
-    type Item = Result<Self, ChunkError>;
+    pub fn data_u64(&mut self, field_name: &str) -> Result<u64, FieldParseError> {
+        self.validate_field_length(8, field_name)?;
+        Ok(self.data.get_u64_le())
+    }
 
-    /// Iterate chunks from a list-like chunk
-    fn next(&mut self) -> Option<Self::Item> {
+    pub(crate) fn typed_iter(self) -> ChunkTypeIter {
+        ChunkTypeIter {
+            chunk: self,
+            ds64: None,
+            decoders: decoders::ChunkDecoders::default(),
+        }
+    }
+
+    /// Pop the next subchunk from a list-like chunk
+    fn next_sized(&mut self, ds64: Option<&ds64::Ds64>) -> Option<Result<Self, ChunkError>> {
         if self.data.is_empty() {
             return None;
         }
 
         let next_chunk: Result<Chunk, ChunkError> =
-            Chunk::pop_from_data(&mut self.data).map_err(|_| {
+            Chunk::pop_from_data_sized(&mut self.data, ds64).map_err(|_| {
+                // A malformed chunk leaves no reliable way to find the one after it
+                self.data.clear();
                 IncorrectChunkError {
                     expected_chunk_code: "Container chunk".to_string(),
                     actual_chunk_code: "Non-container chunk".to_string(),
@@ -203,34 +311,75 @@ impl Iterator for Chunk {
     }
 }
 
+impl Iterator for Chunk {
+    type Item = Result<Self, ChunkError>;
+
+    /// Iterate chunks from a list-like chunk
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_sized(None)
+    }
+}
+
 // A Chunk Iterator that returns ChunkTypes
 pub(crate) struct ChunkTypeIter {
     chunk: Chunk,
+    ds64: Option<ds64::Ds64>,
+    decoders: decoders::ChunkDecoders,
 }
 
 impl Iterator for ChunkTypeIter {
     type Item = Result<ChunkType, ChunkError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.chunk
-            .next()
-            .map(|res| res.map_or_else(Err, |chunk| chunk.load_type()))
+        self.chunk.next_sized(self.ds64.as_ref()).map(|res| {
+            match res.and_then(Chunk::load_type)? {
+                ChunkType::Unknown(chunk) => Ok(chunk.decode_unknown(&self.decoders)),
+                chunk => Ok(chunk),
+            }
+        })
     }
 }
 
 impl ChunkTypeIter {
-    /// Like `next`, but unwraps an error result to None
-    pub fn next_ok(&mut self) -> Option<ChunkType> {
-        self.next().transpose().ok().flatten()
+    /// Resolve the sizes of subchunks larger than 4GB from a ds64 chunk
+    pub fn with_ds64(mut self, ds64: Option<ds64::Ds64>) -> Self {
+        self.ds64 = ds64;
+        self
+    }
+
+    /// Decode chunks this crate doesn't understand with the given decoders
+    pub fn with_decoders(mut self, decoders: &decoders::ChunkDecoders) -> Self {
+        self.decoders = decoders.clone();
+        self
     }
 }
 
 #[derive(Debug, PartialEq)]
-pub enum ChunkType {
+pub(crate) enum ChunkType {
+    Ds64(ds64::Ds64),
     Fmt(fmt::Fmt),
     Fact(fact::Fact),
     Data(Chunk),
     List(list::List),
+    Cue(cue::Cue),
+    Plst(plst::Plst),
+    Smpl(smpl::SamplerInfo),
+    Inst(inst::InstrumentInfo),
+    Acid(acid::AcidInfo),
+    Cart(Chunk),
+    Id3(id3::Id3Tag),
+    Xmp(xmp::XmpInfo),
+    Levl(levl::PeakEnvelope),
+    Chna(chna::Chna),
+    Axml(axml::Axml),
+    Dbmd(dbmd::DolbyMetadata),
+    Cset(cset::Cset),
+    Bext(Chunk),
+    Ixml(ixml::IxmlInfo),
+    Filler(Chunk),
+    #[cfg(feature = "python")]
+    Decoded(String, decoders::PyDecodeResult),
+    Custom(String, Result<Box<dyn decoders::DecodedValue>, ChunkError>),
     #[allow(dead_code)] // This will be read in future
     Unknown(Chunk),
 }
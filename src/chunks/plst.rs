@@ -0,0 +1,34 @@
+use super::Chunk;
+use crate::errors::ChunkError;
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct Plst {
+    pub segments: Vec<PlaylistSegment>,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct PlaylistSegment {
+    pub cue_id: u32,
+    pub length: u32,
+    pub loops: u32,
+}
+
+impl TryFrom<Chunk> for Plst {
+    type Error = ChunkError;
+
+    fn try_from(mut chunk: Chunk) -> Result<Self, Self::Error> {
+        chunk.validate_type("plst")?;
+
+        let segment_count = chunk.data_u32("dwSegments")?;
+        let mut segments = Vec::new();
+        for _ in 0..segment_count {
+            segments.push(PlaylistSegment {
+                cue_id: chunk.data_u32("dwName")?,
+                length: chunk.data_u32("dwLength")?,
+                loops: chunk.data_u32("dwLoops")?,
+            });
+        }
+
+        Ok(Self { segments })
+    }
+}
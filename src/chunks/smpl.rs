@@ -0,0 +1,96 @@
+use num_enum::TryFromPrimitive;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use super::Chunk;
+use crate::errors::ChunkError;
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SamplerInfo {
+    pub manufacturer: u32,
+    pub product: u32,
+    pub sample_period: u32,
+    pub midi_unity_note: u32,
+    pub midi_pitch_fraction: u32,
+    pub smpte_format: u32,
+    pub smpte_offset: String,
+    pub loops: Vec<SampleLoop>,
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SampleLoop {
+    pub id: u32,
+    pub loop_type: LoopType,
+    pub start: u32,
+    pub end: u32,
+    pub fraction: u32,
+    pub play_count: u32,
+}
+
+#[cfg_attr(feature = "python", pyclass(eq, eq_int, frozen, module = "waveinfo"))]
+#[derive(PartialEq, Clone, Copy, Debug, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum LoopType {
+    FORWARD = 0,
+    ALTERNATING = 1,
+    BACKWARD = 2,
+    // Reserved or manufacturer specific loop types
+    UNKNOWN = 0xFFFFFFFF,
+}
+
+impl LoopType {
+    pub fn from_code(loop_type: u32) -> Self {
+        Self::try_from(loop_type).unwrap_or(Self::UNKNOWN)
+    }
+}
+
+impl TryFrom<Chunk> for SamplerInfo {
+    type Error = ChunkError;
+
+    fn try_from(mut chunk: Chunk) -> Result<Self, Self::Error> {
+        chunk.validate_type("smpl")?;
+
+        let manufacturer = chunk.data_u32("dwManufacturer")?;
+        let product = chunk.data_u32("dwProduct")?;
+        let sample_period = chunk.data_u32("dwSamplePeriod")?;
+        let midi_unity_note = chunk.data_u32("dwMIDIUnityNote")?;
+        let midi_pitch_fraction = chunk.data_u32("dwMIDIPitchFraction")?;
+        let smpte_format = chunk.data_u32("dwSMPTEFormat")?;
+        // Packed as hours (signed), minutes, seconds and frames from most to least significant byte
+        let [frames, seconds, minutes, hours] = chunk.data_bytes::<4>("dwSMPTEOffset")?;
+        let loop_count = chunk.data_u32("cSampleLoops")?;
+        chunk.data_u32("cbSamplerData")?;
+
+        let mut loops = Vec::new();
+        for _ in 0..loop_count {
+            loops.push(SampleLoop {
+                id: chunk.data_u32("dwIdentifier")?,
+                loop_type: LoopType::from_code(chunk.data_u32("dwType")?),
+                start: chunk.data_u32("dwStart")?,
+                end: chunk.data_u32("dwEnd")?,
+                fraction: chunk.data_u32("dwFraction")?,
+                play_count: chunk.data_u32("dwPlayCount")?,
+            });
+        }
+
+        Ok(Self {
+            manufacturer,
+            product,
+            sample_period,
+            midi_unity_note,
+            midi_pitch_fraction,
+            smpte_format,
+            smpte_offset: format!(
+                "{:02}:{:02}:{:02}:{:02}",
+                hours as i8, minutes, seconds, frames
+            ),
+            loops,
+        })
+    }
+}
@@ -1,6 +1,7 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, io::Write};
 
 use bytes::Bytes;
+use flate2::{write::GzEncoder, Compression};
 
 use super::*;
 
@@ -39,6 +40,33 @@ fn pop_with_padding_byte() {
     assert_eq!(chunk_data, Bytes::from_static(&[0x42, 0x41, 0x54]));
 }
 
+#[test]
+fn pop_with_ds64_size() {
+    let ds64 = ds64::Ds64 {
+        riff_size: 0,
+        data_size: 0,
+        sample_count: 0,
+        table: HashMap::from([("TEST".to_string(), 4)]),
+    };
+    let mut chunk_data = Bytes::from_static(b"TEST\xff\xff\xff\xffDATA");
+    let chunk = Chunk::pop_from_data_sized(&mut chunk_data, Some(&ds64)).unwrap();
+    assert_eq!(chunk.size, 4);
+    assert_eq!(chunk.data, Bytes::from_static(b"DATA"));
+}
+
+#[test]
+fn iter_stops_at_malformed_chunk() {
+    let mut list_chunk = Chunk {
+        id: "LIST".to_string(),
+        size: 19,
+        data: Bytes::from_static(b"TEST\x02\x00\x00\x00DABAD\xff\x00\x00\x00XX"),
+    };
+
+    assert!(list_chunk.next().unwrap().is_ok());
+    assert!(list_chunk.next().unwrap().is_err());
+    assert!(list_chunk.next().is_none());
+}
+
 #[test]
 fn data_bytes() {
     let mut chunk = Chunk {
@@ -53,16 +81,37 @@ fn data_bytes() {
 }
 
 #[test]
-fn pop_zstring() {
-    let mut chunk = Chunk {
-        id: "".to_string(),
-        size: 8,
-        data: Bytes::from_static(&[0x54, 0x45, 0x53, 0x54, 0x00, 0x44, 0x41, 0x54, 0x41]),
-    };
+fn decode_text() {
+    let charset = cset::Charset::default();
+    assert_eq!(
+        charset.decode(b"Caf\xc3\xa9\x00"),
+        ("Café".to_string(), "UTF-8")
+    );
+    assert_eq!(
+        charset.decode(b"Caf\xe9\x00\x00"),
+        ("Café".to_string(), "windows-1252")
+    );
+    assert_eq!(
+        charset.decode(b"\xff\xfeC\x00a\x00f\x00\xe9\x00\x00\x00"),
+        ("Café".to_string(), "UTF-16LE")
+    );
+
+    let shift_jis = cset::Charset::with_fallback("shift_jis").unwrap();
+    assert_eq!(
+        shift_jis.decode(b"\x83\x65\x83\x58\x83\x67\x00"),
+        ("テスト".to_string(), "Shift_JIS")
+    );
 
-    assert_eq!(chunk.data_zstring("test").unwrap(), "TEST".to_string());
-    assert_eq!(chunk.data, Bytes::from_static(&[0x44, 0x41, 0x54, 0x41]));
-    assert!(chunk.data_zstring("test").is_err());
+    let cset = cset::Cset {
+        code_page: 1251,
+        country_code: 7,
+        language: 25,
+        dialect: 1,
+    };
+    assert_eq!(
+        charset.with_cset(&cset).decode(b"\xd2\xe5\xf1\xf2\x00"),
+        ("Тест".to_string(), "windows-1251")
+    );
 }
 
 #[test]
@@ -78,6 +127,27 @@ fn to_fact_chunk() {
     assert_eq!(chunk, expected_chunk);
 }
 
+#[test]
+fn to_ds64_chunk() {
+    let mut chunk_data = Bytes::from_static(&[
+        0x64, 0x73, 0x36, 0x34, 0x28, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x40, 0x01, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x40, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x50, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x61, 0x78, 0x6d, 0x6c, 0x00, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00,
+    ]);
+    let chunk = Chunk::pop_from_data(&mut chunk_data)
+        .unwrap()
+        .load_type()
+        .unwrap();
+    let expected_chunk = ChunkType::Ds64(ds64::Ds64 {
+        riff_size: 0x1_4000_0024,
+        data_size: 0x1_4000_0000,
+        sample_count: 0x5000_0000,
+        table: HashMap::from([("axml".to_string(), 0x2_0000_0000)]),
+    });
+    assert_eq!(chunk, expected_chunk);
+}
+
 #[test]
 fn to_std_fmt_chunk() {
     let mut chunk_data = Bytes::from_static(&[
@@ -211,13 +281,1019 @@ fn info_list() {
         .unwrap();
     match chunk {
         ChunkType::List(list_chunk) => {
-            let chunk_hashmap: Result<HashMap<String, String>, _> = list_chunk.try_into();
-            assert!(chunk_hashmap.is_ok());
             assert_eq!(
-                chunk_hashmap.unwrap(),
-                HashMap::from([("Software".to_string(), "Lavf61.1.100".to_string())])
+                list_chunk.info_entries(&cset::Charset::default()),
+                Ok(vec![list::InfoEntry {
+                    id: "ISFT".to_string(),
+                    label: Some("Software".to_string()),
+                    value: "Lavf61.1.100".to_string(),
+                    encoding: "UTF-8".to_string(),
+                }])
             );
         }
         _ => panic!("Not an Info chunk: {:?}", chunk),
     }
 }
+
+fn padded<const N: usize>(value: &[u8]) -> [u8; N] {
+    let mut field = [0; N];
+    field[..value.len()].copy_from_slice(value);
+    field
+}
+
+#[test]
+fn to_bext_chunk() {
+    let chunk_body = [
+        padded::<256>(b"Test description").as_slice(),
+        &padded::<32>(b"waveinfo"),
+        &padded::<32>(b"REF0001"),
+        b"2024:09:24",
+        b"12:34:56",
+        &48000u32.to_le_bytes(),
+        &1u32.to_le_bytes(),
+        &2u16.to_le_bytes(),
+        &padded::<64>(&[0xab; 32]),
+        &(-2300i16).to_le_bytes(),
+        &0x7fffi16.to_le_bytes(),
+        &(-100i16).to_le_bytes(),
+        &(-1800i16).to_le_bytes(),
+        &(-2050i16).to_le_bytes(),
+        &[0; 180],
+        b"A=PCM,F=48000,W=24\r\n\0",
+    ]
+    .concat();
+    let chunk = Chunk {
+        id: "bext".to_string(),
+        size: chunk_body.len(),
+        data: Bytes::from(chunk_body),
+    };
+
+    let expected_bext = bext::BextInfo {
+        description: "Test description".to_string(),
+        originator: "waveinfo".to_string(),
+        originator_reference: "REF0001".to_string(),
+        origination_date: "2024:09:24".to_string(),
+        origination_time: "12:34:56".to_string(),
+        time_reference: 0x1_0000_bb80,
+        version: 2,
+        umid: Some("ab".repeat(32)),
+        loudness_value: Some(-23.0),
+        loudness_range: None,
+        max_true_peak_level: Some(-1.0),
+        max_momentary_loudness: Some(-18.0),
+        max_short_term_loudness: Some(-20.5),
+        coding_history: "A=PCM,F=48000,W=24\r\n".to_string(),
+    };
+    assert_eq!(
+        bext::BextInfo::from_chunk(chunk, &cset::Charset::default()).unwrap(),
+        expected_bext
+    );
+}
+
+#[test]
+fn bext_non_ascii_text() {
+    let chunk_body = [
+        padded::<256>(b"Caf\xe9 ambience").as_slice(),
+        &padded::<32>("Müller".as_bytes()),
+        &padded::<32>(b"REF0001"),
+        b"2024:09:24",
+        b"12:34:56",
+        &[0; 10],
+        &[0; 64],
+        &[0; 10],
+        &[0; 180],
+    ]
+    .concat();
+    let chunk = || Chunk {
+        id: "bext".to_string(),
+        size: chunk_body.len(),
+        data: Bytes::from(chunk_body.clone()),
+    };
+
+    let bext = bext::BextInfo::from_chunk(chunk(), &cset::Charset::default()).unwrap();
+    assert_eq!(bext.description, "Café ambience");
+    assert_eq!(bext.originator, "Müller");
+    assert_eq!(bext.origination_date, "2024:09:24");
+
+    // Text that isn't UTF-8 follows the fallback encoding given for the file
+    let charset = cset::Charset::with_fallback("windows-1251").unwrap();
+    let bext = bext::BextInfo::from_chunk(chunk(), &charset).unwrap();
+    assert_eq!(bext.description, "Cafй ambience");
+    assert_eq!(bext.originator, "Müller");
+}
+
+#[test]
+fn to_ixml_chunk() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<BWFXML>
+  <IXML_VERSION>2.10</IXML_VERSION>
+  <PROJECT>Feature</PROJECT>
+  <SCENE>12A</SCENE>
+  <TAKE>3</TAKE>
+  <TAPE>DAY04</TAPE>
+  <CIRCLED>TRUE</CIRCLED>
+  <SPEED>
+    <MASTER_SPEED>24000/1001</MASTER_SPEED>
+    <CURRENT_SPEED>24000/1001</CURRENT_SPEED>
+    <TIMECODE_RATE>24000/1001</TIMECODE_RATE>
+    <TIMECODE_FLAG>NDF</TIMECODE_FLAG>
+    <FILE_SAMPLE_RATE>48000</FILE_SAMPLE_RATE>
+    <AUDIO_BIT_DEPTH>24</AUDIO_BIT_DEPTH>
+    <TIMESTAMP_SAMPLES_SINCE_MIDNIGHT_HI>1</TIMESTAMP_SAMPLES_SINCE_MIDNIGHT_HI>
+    <TIMESTAMP_SAMPLES_SINCE_MIDNIGHT_LO>48000</TIMESTAMP_SAMPLES_SINCE_MIDNIGHT_LO>
+    <TIMESTAMP_SAMPLE_RATE>48000</TIMESTAMP_SAMPLE_RATE>
+  </SPEED>
+  <TRACK_LIST>
+    <TRACK_COUNT>2</TRACK_COUNT>
+    <TRACK>
+      <CHANNEL_INDEX>1</CHANNEL_INDEX>
+      <INTERLEAVE_INDEX>1</INTERLEAVE_INDEX>
+      <NAME>Boom</NAME>
+    </TRACK>
+    <TRACK>
+      <CHANNEL_INDEX>2</CHANNEL_INDEX>
+      <INTERLEAVE_INDEX>2</INTERLEAVE_INDEX>
+      <NAME>Lav 1</NAME>
+      <FUNCTION>Lav</FUNCTION>
+    </TRACK>
+  </TRACK_LIST>
+</BWFXML>"#;
+    let chunk = Chunk {
+        id: "iXML".to_string(),
+        size: xml.len() + 1,
+        data: Bytes::from([xml.as_bytes(), &[0]].concat()),
+    };
+
+    let expected_chunk = ChunkType::Ixml(ixml::IxmlInfo {
+        version: Some("2.10".to_string()),
+        project: Some("Feature".to_string()),
+        scene: Some("12A".to_string()),
+        take: Some("3".to_string()),
+        tape: Some("DAY04".to_string()),
+        circled: Some(true),
+        note: None,
+        speed: Some(ixml::IxmlSpeed {
+            master_speed: Some(24000.0 / 1001.0),
+            current_speed: Some(24000.0 / 1001.0),
+            timecode_rate: Some(24000.0 / 1001.0),
+            drop_frame: Some(false),
+            file_sample_rate: Some(48000),
+            audio_bit_depth: Some(24),
+            digitizer_sample_rate: None,
+            timestamp_samples_since_midnight: Some(0x1_0000_bb80),
+            timestamp_sample_rate: Some(48000),
+            note: None,
+        }),
+        tracks: vec![
+            ixml::IxmlTrack {
+                channel_index: Some(1),
+                interleave_index: Some(1),
+                name: Some("Boom".to_string()),
+                function: None,
+            },
+            ixml::IxmlTrack {
+                channel_index: Some(2),
+                interleave_index: Some(2),
+                name: Some("Lav 1".to_string()),
+                function: Some("Lav".to_string()),
+            },
+        ],
+        xml: xml.to_string(),
+    });
+    assert_eq!(chunk.load_type().unwrap(), expected_chunk);
+}
+
+#[test]
+fn to_cue_chunk() {
+    let mut chunk_data = Bytes::from_static(
+        b"cue \x1c\x00\x00\x00\x01\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00data\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x80\xbb\x00\x00",
+    );
+    let chunk = Chunk::pop_from_data(&mut chunk_data)
+        .unwrap()
+        .load_type()
+        .unwrap();
+    let expected_chunk = ChunkType::Cue(cue::Cue {
+        points: vec![cue::CuePointEntry {
+            id: 1,
+            position: 0,
+            data_chunk_id: "data".to_string(),
+            chunk_start: 0,
+            block_start: 0,
+            sample_offset: 48000,
+        }],
+    });
+    assert_eq!(chunk, expected_chunk);
+}
+
+#[test]
+fn adtl_list() {
+    let mut chunk_data = Bytes::from_static(
+        b"LIST\x42\x00\x00\x00adtl\
+        labl\x0a\x00\x00\x00\x01\x00\x00\x00Intro\x00\
+        note\x08\x00\x00\x00\x01\x00\x00\x00Cue\x00\
+        ltxt\x14\x00\x00\x00\x01\x00\x00\x00\x00\x77\x01\x00rgn \x00\x00\x00\x00\x00\x00\x00\x00",
+    );
+    let chunk = Chunk::pop_from_data(&mut chunk_data)
+        .unwrap()
+        .load_type()
+        .unwrap();
+    match chunk {
+        ChunkType::List(list_chunk) => {
+            assert_eq!(
+                list_chunk
+                    .associated_data(&cset::Charset::default())
+                    .unwrap(),
+                vec![
+                    list::AssociatedData::Label {
+                        cue_id: 1,
+                        text: "Intro".to_string()
+                    },
+                    list::AssociatedData::Note {
+                        cue_id: 1,
+                        text: "Cue".to_string()
+                    },
+                    list::AssociatedData::LabelledText {
+                        cue_id: 1,
+                        sample_length: 96000,
+                        purpose: "rgn ".to_string(),
+                        text: "".to_string()
+                    },
+                ]
+            );
+        }
+        _ => panic!("Not a List chunk: {:?}", chunk),
+    }
+}
+
+#[test]
+fn adtl_list_charset() {
+    let mut chunk_data = Bytes::from_static(
+        b"LIST\x22\x00\x00\x00adtl\
+        labl\x08\x00\x00\x00\x01\x00\x00\x00\xcf\xf0\xe8\x00\
+        note\x05\x00\x00\x00\x02\x00\x00\x00\xe9\x00",
+    );
+    let ChunkType::List(list_chunk) = Chunk::pop_from_data(&mut chunk_data)
+        .unwrap()
+        .load_type()
+        .unwrap()
+    else {
+        panic!("Not a List chunk");
+    };
+
+    assert_eq!(
+        list_chunk
+            .associated_data(&cset::Charset::with_fallback("windows-1251").unwrap())
+            .unwrap(),
+        vec![
+            list::AssociatedData::Label {
+                cue_id: 1,
+                text: "При".to_string()
+            },
+            list::AssociatedData::Note {
+                cue_id: 2,
+                text: "й".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn to_plst_chunk() {
+    let mut chunk_data = Bytes::from_static(
+        b"plst\x10\x00\x00\x00\x01\x00\x00\x00\x01\x00\x00\x00\x80\xbb\x00\x00\x02\x00\x00\x00",
+    );
+    let chunk = Chunk::pop_from_data(&mut chunk_data)
+        .unwrap()
+        .load_type()
+        .unwrap();
+    let expected_chunk = ChunkType::Plst(plst::Plst {
+        segments: vec![plst::PlaylistSegment {
+            cue_id: 1,
+            length: 48000,
+            loops: 2,
+        }],
+    });
+    assert_eq!(chunk, expected_chunk);
+}
+
+#[test]
+fn to_smpl_chunk() {
+    let chunk_body = [
+        0x47u32,
+        0x01,
+        20833,
+        60,
+        0x8000_0000,
+        25,
+        0x01020304,
+        1,
+        0,
+        0,
+        1,
+        1000,
+        47999,
+        0,
+        0,
+    ]
+    .map(u32::to_le_bytes)
+    .concat();
+    let chunk = Chunk {
+        id: "smpl".to_string(),
+        size: chunk_body.len(),
+        data: Bytes::from(chunk_body),
+    };
+
+    let expected_chunk = ChunkType::Smpl(smpl::SamplerInfo {
+        manufacturer: 0x47,
+        product: 0x01,
+        sample_period: 20833,
+        midi_unity_note: 60,
+        midi_pitch_fraction: 0x8000_0000,
+        smpte_format: 25,
+        smpte_offset: "01:02:03:04".to_string(),
+        loops: vec![smpl::SampleLoop {
+            id: 0,
+            loop_type: smpl::LoopType::ALTERNATING,
+            start: 1000,
+            end: 47999,
+            fraction: 0,
+            play_count: 0,
+        }],
+    });
+    assert_eq!(chunk.load_type().unwrap(), expected_chunk);
+}
+
+#[test]
+fn to_inst_chunk() {
+    let mut chunk_data =
+        Bytes::from_static(b"inst\x07\x00\x00\x00\x3c\xfb\x03\x30\x48\x01\x7f\x00");
+    let chunk = Chunk::pop_from_data(&mut chunk_data)
+        .unwrap()
+        .load_type()
+        .unwrap();
+    let expected_chunk = ChunkType::Inst(inst::InstrumentInfo {
+        unshifted_note: 60,
+        fine_tune: -5,
+        gain: 3,
+        low_note: 48,
+        high_note: 72,
+        low_velocity: 1,
+        high_velocity: 127,
+    });
+    assert_eq!(chunk, expected_chunk);
+    assert!(chunk_data.is_empty());
+}
+
+#[test]
+fn to_acid_chunk() {
+    let mut chunk_data = Bytes::from_static(&[
+        0x61, 0x63, 0x69, 0x64, 0x18, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x39, 0x00, 0x00,
+        0x80, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x04, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0xf0, 0x42,
+    ]);
+    let chunk = Chunk::pop_from_data(&mut chunk_data)
+        .unwrap()
+        .load_type()
+        .unwrap();
+    let expected_chunk = ChunkType::Acid(acid::AcidInfo {
+        one_shot: false,
+        root_note: Some(57),
+        stretch: true,
+        disk_based: false,
+        beats: 8,
+        meter_numerator: 4,
+        meter_denominator: 4,
+        tempo: 120.0,
+    });
+    assert_eq!(chunk, expected_chunk);
+}
+
+#[test]
+fn to_cart_chunk() {
+    let chunk_body = [
+        b"0101".as_slice(),
+        &padded::<64>(b"Station ID"),
+        &padded::<64>(b"Announcer"),
+        &padded::<64>(b"ID-0042"),
+        &padded::<64>(b""),
+        &padded::<64>(b"IDENT"),
+        &padded::<64>(b""),
+        &padded::<64>(b"...and now"),
+        b"2024-01-01",
+        b"00:00:00",
+        b"2024-12-31",
+        b"23:59:59",
+        &padded::<64>(b"waveinfo"),
+        &padded::<64>(b"0.4.0"),
+        &padded::<64>(b""),
+        &(-2000i32).to_le_bytes(),
+        b"INTs",
+        &0u32.to_le_bytes(),
+        b"INTe",
+        &96000u32.to_le_bytes(),
+        b"SEGs",
+        &432000u32.to_le_bytes(),
+        &[0; 40],
+        &[0; 276],
+        &padded::<1024>(b"https://example.com"),
+        b"Tag text\r\n",
+    ]
+    .concat();
+    let chunk = Chunk {
+        id: "cart".to_string(),
+        size: chunk_body.len(),
+        data: Bytes::from(chunk_body),
+    };
+
+    let cart_info = cart::Cart::from_chunk(chunk, &cset::Charset::default())
+        .unwrap()
+        .with_sample_rate(48000);
+    assert_eq!(
+        cart_info,
+        cart::CartInfo {
+            version: "0101".to_string(),
+            title: "Station ID".to_string(),
+            artist: "Announcer".to_string(),
+            cut_id: "ID-0042".to_string(),
+            client_id: "".to_string(),
+            category: "IDENT".to_string(),
+            classification: "".to_string(),
+            out_cue: "...and now".to_string(),
+            start_date: "2024-01-01".to_string(),
+            start_time: "00:00:00".to_string(),
+            end_date: "2024-12-31".to_string(),
+            end_time: "23:59:59".to_string(),
+            producer_app_id: "waveinfo".to_string(),
+            producer_app_version: "0.4.0".to_string(),
+            user_def: "".to_string(),
+            level_reference: -2000,
+            timers: vec![
+                cart::CartTimer {
+                    usage: "INTs".to_string(),
+                    sample_offset: 0,
+                    time: Some(0.0),
+                },
+                cart::CartTimer {
+                    usage: "INTe".to_string(),
+                    sample_offset: 96000,
+                    time: Some(2.0),
+                },
+                cart::CartTimer {
+                    usage: "SEGs".to_string(),
+                    sample_offset: 432000,
+                    time: Some(9.0),
+                },
+            ],
+            url: "https://example.com".to_string(),
+            tag_text: "Tag text\r\n".to_string(),
+        }
+    );
+}
+
+#[test]
+fn cart_non_ascii_text() {
+    let chunk_body = [
+        b"0101".as_slice(),
+        &padded::<64>(b"Caf\xe9 jingle"),
+        &padded::<64>("Zoë".as_bytes()),
+        &[0; 64 * 5 + 36 + 64 * 3 + 4 + 8 * 8 + 276 + 1024],
+    ]
+    .concat();
+    let chunk = || Chunk {
+        id: "cart".to_string(),
+        size: chunk_body.len(),
+        data: Bytes::from(chunk_body.clone()),
+    };
+
+    let cart_info = cart::Cart::from_chunk(chunk(), &cset::Charset::default())
+        .unwrap()
+        .with_sample_rate(48000);
+    assert_eq!(cart_info.title, "Café jingle");
+    assert_eq!(cart_info.artist, "Zoë");
+    assert!(cart_info.timers.is_empty());
+
+    let charset = cset::Charset::with_fallback("windows-1251").unwrap();
+    let cart_info = cart::Cart::from_chunk(chunk(), &charset)
+        .unwrap()
+        .with_sample_rate(48000);
+    assert_eq!(cart_info.title, "Cafй jingle");
+}
+
+#[test]
+fn cart_timers_without_sample_rate() {
+    let chunk_body = [
+        b"0101".as_slice(),
+        &[0; 64 * 7 + 36 + 64 * 3 + 4],
+        b"INTe",
+        &96000u32.to_le_bytes(),
+        &[0; 8 * 7 + 276 + 1024],
+    ]
+    .concat();
+    let chunk = Chunk {
+        id: "cart".to_string(),
+        size: chunk_body.len(),
+        data: Bytes::from(chunk_body),
+    };
+
+    let cart_info = cart::Cart::from_chunk(chunk, &cset::Charset::default())
+        .unwrap()
+        .with_sample_rate(0);
+    assert_eq!(
+        cart_info.timers,
+        vec![cart::CartTimer {
+            usage: "INTe".to_string(),
+            sample_offset: 96000,
+            time: None,
+        }]
+    );
+
+    #[cfg(feature = "serde")]
+    assert_eq!(
+        serde_json::to_value(&cart_info.timers[0]).unwrap()["time"],
+        serde_json::Value::Null
+    );
+
+    #[cfg(feature = "python")]
+    {
+        use pyo3::prelude::*;
+
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let timer = Bound::new(py, cart_info.timers[0].clone()).unwrap();
+            assert!(timer.getattr("time").unwrap().is_none());
+        });
+    }
+}
+
+#[test]
+fn to_id3_chunk() {
+    fn frame(id: &[u8], body: &[u8]) -> Vec<u8> {
+        [id, &(body.len() as u32).to_be_bytes(), &[0, 0], body].concat()
+    }
+
+    let frames = [
+        frame(b"TIT2", b"\x03Caf\xc3\xa9"),
+        frame(b"TPE1", b"\x01\xff\xfeA\x00r\x00t\x00"),
+        frame(b"TRCK", b"\x003/12"),
+        frame(b"TSRC", b"\x00GBAYE0000001"),
+        frame(b"TXXX", b"\x00MOOD\x00Calm"),
+        frame(b"COMM", b"\x00engDesc\x00Comment text"),
+        frame(b"APIC", b"\x00image/png\x00\x03Cover\x00\x89PNG"),
+    ]
+    .concat();
+    let chunk_body = [
+        b"ID3\x03\x00\x00".as_slice(),
+        &[0, 0, (frames.len() >> 7) as u8, (frames.len() & 0x7f) as u8],
+        &frames,
+        &[0; 16],
+    ]
+    .concat();
+    let chunk = Chunk {
+        id: "id3 ".to_string(),
+        size: chunk_body.len(),
+        data: Bytes::from(chunk_body),
+    };
+
+    let expected_chunk = ChunkType::Id3(id3::Id3Tag {
+        version: "2.3.0".to_string(),
+        title: Some("Caf\u{e9}".to_string()),
+        artist: Some("Art".to_string()),
+        album: None,
+        track_number: Some(3),
+        track_total: Some(12),
+        isrc: Some("GBAYE0000001".to_string()),
+        comments: vec![id3::Id3Comment {
+            language: "eng".to_string(),
+            description: "Desc".to_string(),
+            text: "Comment text".to_string(),
+        }],
+        pictures: vec![id3::Id3Picture {
+            mime_type: "image/png".to_string(),
+            picture_type: 3,
+            description: "Cover".to_string(),
+            data: b"\x89PNG".to_vec(),
+        }],
+        text_frames: HashMap::from([
+            ("TIT2".to_string(), "Caf\u{e9}".to_string()),
+            ("TPE1".to_string(), "Art".to_string()),
+            ("TRCK".to_string(), "3/12".to_string()),
+            ("TSRC".to_string(), "GBAYE0000001".to_string()),
+            ("TXXX:MOOD".to_string(), "Calm".to_string()),
+        ]),
+    });
+    assert_eq!(chunk.load_type().unwrap(), expected_chunk);
+}
+
+#[test]
+fn to_xmp_chunk() {
+    let xml = r#"<?xpacket begin="" id="W5M0MpCehiHzreSzNTczkc9d"?>
+<x:xmpmeta xmlns:x="adobe:ns:meta/">
+  <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+    <rdf:Description rdf:about=""
+        xmlns:dc="http://purl.org/dc/elements/1.1/"
+        xmlns:xmpDM="http://ns.adobe.com/xmp/1.0/DynamicMedia/"
+        xmlns:xmpRights="http://ns.adobe.com/xap/1.0/rights/"
+        dc:format="audio/x-wav"
+        xmpDM:album="Album"
+        xmpRights:Marked="True">
+      <dc:title>
+        <rdf:Alt>
+          <rdf:li xml:lang="x-default">Title</rdf:li>
+        </rdf:Alt>
+      </dc:title>
+      <dc:creator>
+        <rdf:Seq>
+          <rdf:li>First</rdf:li>
+          <rdf:li>Second</rdf:li>
+        </rdf:Seq>
+      </dc:creator>
+      <xmpDM:genre>Ambient</xmpDM:genre>
+      <xmpDM:Tracks>
+        <rdf:Bag>
+          <rdf:li>
+            <rdf:Description xmpDM:trackName="CuePoint Markers" xmpDM:frameRate="f48000">
+              <xmpDM:markers>
+                <rdf:Seq>
+                  <rdf:li xmpDM:startTime="96000" xmpDM:name="Verse"/>
+                  <rdf:li rdf:parseType="Resource">
+                    <xmpDM:startTime>192000</xmpDM:startTime>
+                    <xmpDM:duration>48000</xmpDM:duration>
+                    <xmpDM:name>Chorus</xmpDM:name>
+                    <xmpDM:type>Cue</xmpDM:type>
+                  </rdf:li>
+                </rdf:Seq>
+              </xmpDM:markers>
+            </rdf:Description>
+          </rdf:li>
+        </rdf:Bag>
+      </xmpDM:Tracks>
+    </rdf:Description>
+  </rdf:RDF>
+</x:xmpmeta>
+<?xpacket end="w"?>"#;
+    let chunk = Chunk {
+        id: "_PMX".to_string(),
+        size: xml.len(),
+        data: Bytes::from_static(xml.as_bytes()),
+    };
+
+    let expected_chunk = ChunkType::Xmp(xmp::XmpInfo {
+        dublin_core: HashMap::from([
+            ("format".to_string(), vec!["audio/x-wav".to_string()]),
+            ("title".to_string(), vec!["Title".to_string()]),
+            (
+                "creator".to_string(),
+                vec!["First".to_string(), "Second".to_string()],
+            ),
+        ]),
+        dynamic_media: HashMap::from([
+            ("album".to_string(), "Album".to_string()),
+            ("genre".to_string(), "Ambient".to_string()),
+        ]),
+        rights: HashMap::from([("Marked".to_string(), "True".to_string())]),
+        markers: vec![
+            xmp::XmpMarker {
+                name: Some("Verse".to_string()),
+                comment: None,
+                marker_type: None,
+                start_time: Some("96000".to_string()),
+                duration: None,
+                frame_rate: Some("f48000".to_string()),
+            },
+            xmp::XmpMarker {
+                name: Some("Chorus".to_string()),
+                comment: None,
+                marker_type: Some("Cue".to_string()),
+                start_time: Some("192000".to_string()),
+                duration: Some("48000".to_string()),
+                frame_rate: Some("f48000".to_string()),
+            },
+        ],
+        xml: xml.to_string(),
+    });
+    assert_eq!(chunk.load_type().unwrap(), expected_chunk);
+}
+
+#[test]
+fn to_levl_chunk() {
+    let chunk_body = [
+        [1u32, 2, 2, 256, 2, 2, 300, 128]
+            .map(u32::to_le_bytes)
+            .concat()
+            .as_slice(),
+        &padded::<28>(b"2024:09:24:12:34:56:789"),
+        &[0; 60],
+        &[100u16, 90, 200, 180, 300, 270, 400, 360]
+            .map(u16::to_le_bytes)
+            .concat(),
+    ]
+    .concat();
+    let chunk = Chunk {
+        id: "levl".to_string(),
+        size: chunk_body.len(),
+        data: Bytes::from(chunk_body),
+    };
+
+    let expected_chunk = ChunkType::Levl(levl::PeakEnvelope {
+        version: 1,
+        format: 2,
+        points_per_value: 2,
+        block_size: 256,
+        channels: 2,
+        frames: 2,
+        peak_of_peaks_position: Some(300),
+        timestamp: "2024:09:24:12:34:56:789".to_string(),
+        positive_peaks: vec![vec![100, 300], vec![200, 400]],
+        negative_peaks: vec![vec![90, 270], vec![180, 360]],
+    });
+    assert_eq!(chunk.load_type().unwrap(), expected_chunk);
+}
+
+#[test]
+fn invalid_levl_sizes() {
+    let levl_chunk = |channels: u32, frames: u32| {
+        let chunk_body = [
+            [1u32, 2, 2, 256, channels, frames, 0, 128]
+                .map(u32::to_le_bytes)
+                .concat()
+                .as_slice(),
+            &[0; 88],
+            &[100u16, 90].map(u16::to_le_bytes).concat(),
+        ]
+        .concat();
+        Chunk {
+            id: "levl".to_string(),
+            size: chunk_body.len(),
+            data: Bytes::from(chunk_body),
+        }
+    };
+
+    let Err(ChunkError::FieldParse(err)) = levl_chunk(0, 1).load_type() else {
+        panic!("Expected zero channels to be rejected");
+    };
+    assert_eq!(err.field_name, "dwPeakChannels");
+
+    let Err(ChunkError::FieldParse(err)) = levl_chunk(2, 1_000_000).load_type() else {
+        panic!("Expected peaks larger than the chunk to be rejected");
+    };
+    assert_eq!(err.field_name, "peaks");
+
+    for frames in [0, u32::MAX] {
+        let Err(ChunkError::FieldParse(err)) = levl_chunk(u32::MAX, frames).load_type() else {
+            panic!("Expected more channels than the chunk holds to be rejected");
+        };
+        assert_eq!(err.field_name, "dwPeakChannels");
+    }
+}
+
+#[test]
+fn to_chna_chunk() {
+    let chunk_body = [
+        b"\x02\x00\x03\x00".as_slice(),
+        b"\x01\x00ATU_00000001AT_00010001_01AP_00010002\x00",
+        b"\x02\x00ATU_00000002AT_00010002_01AP_00010002\x00",
+        b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    ]
+    .concat();
+    let chunk = Chunk {
+        id: "chna".to_string(),
+        size: chunk_body.len(),
+        data: Bytes::from(chunk_body),
+    };
+
+    let expected_chunk = ChunkType::Chna(chna::Chna {
+        tracks: vec![
+            chna::AdmTrack {
+                track_index: 1,
+                uid: "ATU_00000001".to_string(),
+                track_format_ref: "AT_00010001_01".to_string(),
+                pack_format_ref: "AP_00010002".to_string(),
+            },
+            chna::AdmTrack {
+                track_index: 2,
+                uid: "ATU_00000002".to_string(),
+                track_format_ref: "AT_00010002_01".to_string(),
+                pack_format_ref: "AP_00010002".to_string(),
+            },
+        ],
+    });
+    assert_eq!(chunk.load_type().unwrap(), expected_chunk);
+}
+
+#[test]
+fn to_bxml_chunk() {
+    let xml = "<ebuCoreMain><coreMetadata/></ebuCoreMain>";
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(xml.as_bytes()).unwrap();
+    let chunk_body = [b"\x01\x00".as_slice(), &encoder.finish().unwrap()].concat();
+    let chunk = Chunk {
+        id: "bxml".to_string(),
+        size: chunk_body.len(),
+        data: Bytes::from(chunk_body),
+    };
+
+    let expected_chunk = ChunkType::Axml(axml::Axml {
+        xml: xml.to_string(),
+    });
+    assert_eq!(chunk.load_type().unwrap(), expected_chunk);
+}
+
+#[test]
+fn bxml_inflate_limit() {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&[b'a'; 100]).unwrap();
+    let chunk_body = encoder.finish().unwrap();
+    let chunk = Chunk {
+        id: "bxml".to_string(),
+        size: chunk_body.len(),
+        data: Bytes::from(chunk_body),
+    };
+
+    assert_eq!(axml::inflate(&chunk, 100).unwrap().len(), 100);
+    assert_eq!(
+        axml::inflate(&chunk, 99),
+        Err(ChunkParseError {
+            chunk_code: "bxml".to_string(),
+            reason: "XML inflates to more than 99 bytes".to_string(),
+        })
+    );
+}
+
+#[test]
+fn to_dbmd_chunk() {
+    let dolby_digital_plus = [0x01, 0x00, 0xea, 0x98, 0xa4, 0x37];
+    let atmos_supplemental = [
+        b"\xbd\x6f\x72\xf8\x02\x00\x00".as_slice(),
+        &padded::<15>(b"\x01\x02\x04\x06"),
+        &[0; 8 * 15],
+        b"\x01\x04",
+    ]
+    .concat();
+    let chunk_body = [
+        b"\x06\x00\x00\x01".as_slice(),
+        b"\x07\x06\x00",
+        &dolby_digital_plus,
+        b"\x00\x0a\x90\x00",
+        &atmos_supplemental,
+        b"\x00\x00",
+    ]
+    .concat();
+    let chunk = Chunk {
+        id: "dbmd".to_string(),
+        size: chunk_body.len(),
+        data: Bytes::from(chunk_body),
+    };
+
+    let trim = |configuration: &str| dbmd::DolbyAtmosTrim {
+        configuration: configuration.to_string(),
+        auto_trim: false,
+        center_trim: -0.0,
+        surround_trim: -0.0,
+        height_trim: -0.0,
+    };
+    let expected_chunk = ChunkType::Dbmd(dbmd::DolbyMetadata {
+        version: "1.0.0.6".to_string(),
+        segments: vec![
+            dbmd::DolbySegment {
+                segment_id: 7,
+                segment_type: dbmd::DolbySegmentType::DOLBY_DIGITAL_PLUS,
+                data: dolby_digital_plus.to_vec(),
+            },
+            dbmd::DolbySegment {
+                segment_id: 10,
+                segment_type: dbmd::DolbySegmentType::DOLBY_ATMOS_SUPPLEMENTAL,
+                data: atmos_supplemental,
+            },
+        ],
+        dolby_digital: None,
+        dolby_digital_plus: Some(dbmd::DolbyDigitalInfo {
+            channel_mode: "3/2".to_string(),
+            lfe: true,
+            dialnorm: -24,
+            center_mix_level: Some(-4.5),
+            surround_mix_level: Some(-6.0),
+            preferred_downmix: Some("Lo/Ro".to_string()),
+            lt_rt_center_mix_level: Some(-3.0),
+            lt_rt_surround_mix_level: Some(-3.0),
+            lo_ro_center_mix_level: Some(-6.0),
+            lo_ro_surround_mix_level: Some(f64::NEG_INFINITY),
+        }),
+        atmos: Some(dbmd::DolbyAtmosInfo {
+            creation_tool: None,
+            creation_tool_version: None,
+            trims: [
+                vec![dbmd::DolbyAtmosTrim {
+                    configuration: "2.0".to_string(),
+                    auto_trim: true,
+                    center_trim: -1.0,
+                    surround_trim: -2.0,
+                    height_trim: -3.0,
+                }],
+                [
+                    "5.1", "7.1", "2.0.2", "5.1.2", "7.1.2", "2.0.4", "5.1.4", "7.1.4",
+                ]
+                .map(trim)
+                .to_vec(),
+            ]
+            .concat(),
+            binaural_render_modes: vec![
+                dbmd::BinauralRenderMode::NEAR,
+                dbmd::BinauralRenderMode::NOT_INDICATED,
+            ],
+        }),
+    });
+    assert_eq!(chunk.load_type().unwrap(), expected_chunk);
+}
+
+#[test]
+fn info_list_entries() {
+    let chunk_body = [
+        b"INFO".as_slice(),
+        b"IART\x06\x00\x00\x00First\x00",
+        b"ITRK\x02\x00\x00\x003\x00",
+        b"IXYZ\x05\x00\x00\x00Odd\x00\x00\x00",
+        b"IART\x07\x00\x00\x00Second\x00\x00",
+    ]
+    .concat();
+    let chunk = Chunk {
+        id: "LIST".to_string(),
+        size: chunk_body.len(),
+        data: Bytes::from(chunk_body),
+    };
+    let entry = |id: &str, label: Option<&str>, value: &str| list::InfoEntry {
+        id: id.to_string(),
+        label: label.map(String::from),
+        value: value.to_string(),
+        encoding: "UTF-8".to_string(),
+    };
+
+    let ChunkType::List(list_chunk) = chunk.load_type().unwrap() else {
+        panic!("Not a LIST chunk");
+    };
+    let entries = list_chunk.info_entries(&cset::Charset::default());
+    assert_eq!(
+        entries.unwrap(),
+        vec![
+            entry("IART", Some("Artist"), "First"),
+            entry("ITRK", Some("Track Number"), "3"),
+            entry("IXYZ", None, "Odd"),
+            entry("IART", Some("Artist"), "Second"),
+        ]
+    );
+}
+
+#[test]
+fn chunk_map() {
+    let file = b"RIFF\x3a\x00\x00\x00WAVE\
+        abcd\x03\x00\x00\x00xyz\x01\
+        LIST\x12\x00\x00\x00INFOISFT\x06\x00\x00\x00Lavf\x00\x00\
+        data\x10\x00\x00\x00\x00\x00\x00\x00";
+    let descriptor = |id: &str,
+                      offset,
+                      declared_size,
+                      actual_size,
+                      padding,
+                      parent: Option<&str>,
+                      depth,
+                      truncated| map::ChunkDescriptor {
+        id: id.to_string(),
+        offset,
+        declared_size,
+        actual_size,
+        padding,
+        parent: parent.map(String::from),
+        depth,
+        truncated,
+    };
+
+    assert_eq!(
+        map::chunk_map(file, None),
+        vec![
+            descriptor("RIFF", 0, 58, 54, None, None, 0, true),
+            descriptor("abcd", 12, 3, 3, Some(1), Some("WAVE"), 1, false),
+            descriptor("LIST", 24, 18, 18, None, Some("WAVE"), 1, false),
+            descriptor("ISFT", 36, 6, 6, None, Some("INFO"), 2, false),
+            descriptor("data", 50, 16, 4, None, Some("WAVE"), 1, true),
+        ]
+    );
+}
+
+#[test]
+fn chunk_map_deep_nesting() {
+    // Each level is a LIST header and type, with the innermost list left empty
+    let levels = 100_000;
+    let mut file = b"RIFF".to_vec();
+    file.extend_from_slice(&(4 + levels as u32 * 12).to_le_bytes());
+    file.extend_from_slice(b"WAVE");
+    for level in 0..levels {
+        file.extend_from_slice(b"LIST");
+        file.extend_from_slice(&(4 + (levels - level - 1) as u32 * 12).to_le_bytes());
+        file.extend_from_slice(b"INFO");
+    }
+
+    let descriptors = map::chunk_map(&file, None);
+    assert_eq!(descriptors.len(), levels + 1);
+    assert_eq!(descriptors.last().unwrap().depth, levels);
+}
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use roxmltree::{Document, Node};
+
+use super::Chunk;
+use crate::errors::{ChunkError, ChunkParseError};
+
+const NS_RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const NS_DC: &str = "http://purl.org/dc/elements/1.1/";
+const NS_XMP_DM: &str = "http://ns.adobe.com/xmp/1.0/DynamicMedia/";
+const NS_XMP_RIGHTS: &str = "http://ns.adobe.com/xap/1.0/rights/";
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XmpInfo {
+    pub dublin_core: HashMap<String, Vec<String>>,
+    pub dynamic_media: HashMap<String, String>,
+    pub rights: HashMap<String, String>,
+    pub markers: Vec<XmpMarker>,
+    pub xml: String,
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XmpMarker {
+    pub name: Option<String>,
+    pub comment: Option<String>,
+    pub marker_type: Option<String>,
+    pub start_time: Option<String>,
+    pub duration: Option<String>,
+    pub frame_rate: Option<String>,
+}
+
+impl TryFrom<Chunk> for XmpInfo {
+    type Error = ChunkError;
+
+    fn try_from(mut chunk: Chunk) -> Result<Self, Self::Error> {
+        chunk.validate_type("_PMX")?;
+
+        let xml = chunk.data_text("XMP")?;
+        let document = Document::parse(&xml).map_err(|err| ChunkParseError {
+            chunk_code: chunk.id.clone(),
+            reason: err.to_string(),
+        })?;
+
+        let mut dublin_core = HashMap::<String, Vec<String>>::new();
+        let mut dynamic_media = HashMap::<String, String>::new();
+        let mut rights = HashMap::<String, String>::new();
+
+        let descriptions = document
+            .descendants()
+            .filter(|node| node.has_tag_name((NS_RDF, "RDF")))
+            .flat_map(|rdf| rdf.children())
+            .filter(|node| node.has_tag_name((NS_RDF, "Description")));
+
+        for description in descriptions {
+            for (namespace, name, values) in properties(description) {
+                match namespace {
+                    NS_DC => {
+                        dublin_core.insert(name.to_string(), values);
+                    }
+                    NS_XMP_DM if !values.is_empty() => {
+                        dynamic_media.insert(name.to_string(), values.join("; "));
+                    }
+                    NS_XMP_RIGHTS => {
+                        rights.insert(name.to_string(), values.join("; "));
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        let markers = document
+            .descendants()
+            .filter(|node| node.has_tag_name((NS_XMP_DM, "markers")))
+            .flat_map(|markers| {
+                // Marker times are counted in frames of their track's frame rate
+                let frame_rate = markers
+                    .parent_element()
+                    .and_then(|track| struct_fields(track).remove("frameRate"));
+                list_items(markers)
+                    .map(struct_fields)
+                    .map(move |mut fields| XmpMarker {
+                        name: fields.remove("name"),
+                        comment: fields.remove("comment"),
+                        marker_type: fields.remove("type"),
+                        start_time: fields.remove("startTime"),
+                        duration: fields.remove("duration"),
+                        frame_rate: frame_rate.clone(),
+                    })
+                    .collect::<Vec<XmpMarker>>()
+            })
+            .collect();
+
+        Ok(Self {
+            dublin_core,
+            dynamic_media,
+            rights,
+            markers,
+            xml,
+        })
+    }
+}
+
+/// Namespace, name and values of each property of a description, in attribute or element form
+fn properties<'a>(description: Node<'a, '_>) -> Vec<(&'a str, &'a str, Vec<String>)> {
+    let attributes = description.attributes().filter_map(|attribute| {
+        Some((
+            attribute.namespace()?,
+            attribute.name(),
+            vec![attribute.value().to_string()],
+        ))
+    });
+    let elements = description
+        .children()
+        .filter(Node::is_element)
+        .filter_map(|element| {
+            let tag_name = element.tag_name();
+            Some((
+                tag_name.namespace()?,
+                tag_name.name(),
+                element_values(element),
+            ))
+        });
+    attributes.chain(elements).collect()
+}
+
+/// Values of a simple property, or each item of an array (rdf:Alt, rdf:Bag or rdf:Seq) property
+fn element_values(element: Node) -> Vec<String> {
+    let items = list_items(element)
+        .filter_map(|item| item.text())
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+        .map(str::to_string)
+        .collect::<Vec<String>>();
+    if !items.is_empty() {
+        return items;
+    }
+    element
+        .text()
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+        .map(|text| vec![text.to_string()])
+        .unwrap_or_default()
+}
+
+/// Items (rdf:li) of an array property
+fn list_items<'a, 'input>(element: Node<'a, 'input>) -> impl Iterator<Item = Node<'a, 'input>> {
+    element
+        .children()
+        .filter(|node| {
+            ["Alt", "Bag", "Seq"]
+                .iter()
+                .any(|array| node.has_tag_name((NS_RDF, *array)))
+        })
+        .flat_map(|array| array.children())
+        .filter(|node| node.has_tag_name((NS_RDF, "li")))
+}
+
+/// Simple xmpDM fields of a struct, which may be nested in an rdf:Description
+fn struct_fields(node: Node) -> HashMap<String, String> {
+    let mut fields = node
+        .children()
+        .filter(|child| child.has_tag_name((NS_RDF, "Description")))
+        .flat_map(struct_fields)
+        .collect::<HashMap<String, String>>();
+    for (namespace, name, values) in properties(node) {
+        if namespace == NS_XMP_DM && !values.is_empty() {
+            fields.insert(name.to_string(), values.join("; "));
+        }
+    }
+    fields
+}
@@ -1,12 +1,14 @@
-use pyo3::PyErr;
+#[cfg(feature = "python")]
+use pyo3::{exceptions::PyValueError, PyErr};
 use std::error::Error;
 use std::fmt::{Debug, Display};
 use std::num::TryFromIntError;
 
+#[cfg(feature = "python")]
 use crate::exceptions::WavLoadError;
 
 #[derive(Debug, PartialEq)]
-pub(crate) struct ChunkParseError {
+pub struct ChunkParseError {
     pub chunk_code: String,
     pub reason: String,
 }
@@ -21,7 +23,7 @@ impl ChunkParseError {
 }
 
 #[derive(Debug, PartialEq)]
-pub(crate) struct FieldParseError {
+pub struct FieldParseError {
     pub chunk_code: String,
     pub field_name: String,
     pub position: usize,
@@ -29,13 +31,13 @@ pub(crate) struct FieldParseError {
 }
 
 #[derive(Debug, PartialEq)]
-pub(crate) struct IncorrectChunkError {
+pub struct IncorrectChunkError {
     pub expected_chunk_code: String,
     pub actual_chunk_code: String,
 }
 
 #[derive(Debug, PartialEq)]
-pub(crate) struct MissingChunkError {
+pub struct MissingChunkError {
     pub expected_chunk_code: String,
 }
 
@@ -48,7 +50,7 @@ impl MissingChunkError {
 }
 
 #[derive(Debug, PartialEq)]
-pub(crate) enum ChunkError {
+pub enum ChunkError {
     ChunkParse(ChunkParseError),
     IncorrectChunk(IncorrectChunkError),
     MissingChunk(MissingChunkError),
@@ -120,59 +122,68 @@ impl From<TryFromIntError> for ChunkError {
 
 impl Error for ChunkError {}
 
+/// An error that stops a file from being loaded at all
 #[derive(Debug)]
-pub(crate) struct FatalError {
-    pub inner: ChunkError,
+pub enum FatalError {
+    Chunk(ChunkError),
+    Io(std::io::Error),
+    UnknownEncoding(String),
+    /// An exception raised by a registered Python decoder
+    #[cfg(feature = "python")]
+    Python(PyErr),
 }
 
 impl Display for FatalError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Display::fmt(&self.inner, f)
+        match self {
+            FatalError::Chunk(err) => Display::fmt(err, f),
+            FatalError::Io(err) => Display::fmt(err, f),
+            FatalError::UnknownEncoding(label) => write!(f, "Unknown encoding: {}", label),
+            #[cfg(feature = "python")]
+            FatalError::Python(err) => Display::fmt(err, f),
+        }
     }
 }
 
+#[cfg(feature = "python")]
 impl From<FatalError> for PyErr {
     fn from(value: FatalError) -> Self {
-        WavLoadError::new_err(value.to_string())
+        match value {
+            FatalError::Io(err) => err.into(),
+            FatalError::UnknownEncoding(_) => PyValueError::new_err(value.to_string()),
+            FatalError::Python(err) => err,
+            err => WavLoadError::new_err(err.to_string()),
+        }
     }
 }
 
-impl From<ChunkError> for FatalError {
-    fn from(value: ChunkError) -> Self {
-        Self { inner: value }
+impl From<std::io::Error> for FatalError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
     }
 }
 
-impl From<ChunkParseError> for FatalError {
-    fn from(value: ChunkParseError) -> Self {
-        Self {
-            inner: value.into(),
-        }
+#[cfg(feature = "python")]
+impl From<PyErr> for FatalError {
+    fn from(value: PyErr) -> Self {
+        Self::Python(value)
     }
 }
 
-impl From<IncorrectChunkError> for FatalError {
-    fn from(value: IncorrectChunkError) -> Self {
-        Self {
-            inner: value.into(),
-        }
+impl<T: Into<ChunkError>> From<T> for FatalError {
+    fn from(value: T) -> Self {
+        Self::Chunk(value.into())
     }
 }
 
-impl From<MissingChunkError> for FatalError {
-    fn from(value: MissingChunkError) -> Self {
-        Self {
-            inner: value.into(),
-        }
-    }
-}
-
-impl From<FieldParseError> for FatalError {
-    fn from(value: FieldParseError) -> Self {
-        Self {
-            inner: value.into(),
+impl Error for FatalError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FatalError::Chunk(err) => Some(err),
+            FatalError::Io(err) => Some(err),
+            FatalError::UnknownEncoding(_) => None,
+            #[cfg(feature = "python")]
+            FatalError::Python(err) => Some(err),
         }
     }
 }
-
-impl Error for FatalError {}
@@ -1,8 +1,18 @@
 use num_enum::TryFromPrimitive;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
+use strum_macros::{Display, EnumString};
 
-#[pyclass(eq, eq_int, frozen, get_all, module = "waveinfo")]
-#[derive(PartialEq, Clone, Copy, Debug, TryFromPrimitive)]
+/// Audio encoding, as given by the format tag.
+///
+/// Formats are displayed, parsed and serialized by their variant name, eg. `PCM` or `IEEE_FLOAT`.
+/// These names won't change between releases.
+#[cfg_attr(
+    feature = "python",
+    pyclass(eq, eq_int, frozen, get_all, module = "waveinfo")
+)]
+#[derive(PartialEq, Clone, Copy, Debug, TryFromPrimitive, Display, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 #[allow(clippy::upper_case_acronyms, non_camel_case_types)]
 pub enum Format {
@@ -1,3 +1,4 @@
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
 mod chunks;
@@ -6,17 +7,88 @@ mod formats;
 mod public;
 mod util;
 
+#[cfg(feature = "python")]
 use public::*;
 
+pub use chunks::{
+    acid::AcidInfo,
+    bext::BextInfo,
+    cart::{CartInfo, CartTimer},
+    chna::AdmTrack,
+    dbmd::{
+        BinauralRenderMode, DolbyAtmosInfo, DolbyAtmosTrim, DolbyDigitalInfo, DolbyMetadata,
+        DolbySegment, DolbySegmentType,
+    },
+    decoders::{ChunkDecoder, ChunkDecoders},
+    id3::{Id3Comment, Id3Picture, Id3Tag},
+    inst::InstrumentInfo,
+    ixml::{IxmlInfo, IxmlSpeed, IxmlTrack},
+    levl::PeakEnvelope,
+    list::InfoEntry,
+    map::ChunkDescriptor,
+    smpl::{LoopType, SampleLoop, SamplerInfo},
+    xmp::{XmpInfo, XmpMarker},
+    Chunk,
+};
+pub use errors::{
+    ChunkError, ChunkParseError, FatalError, FieldParseError, IncorrectChunkError,
+    MissingChunkError,
+};
+pub use public::{
+    adm::{AdmContent, AdmInfo, AdmObject, AdmPackFormat, AdmProgramme},
+    cue::{CuePoint, PlaylistEntry},
+    detail::{Container, RawDetail, SpeakerPosition, WavDetail},
+    wave::WavFile,
+    Format,
+};
+
+#[cfg(feature = "python")]
 #[pymodule]
 fn waveinfo(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(chunks::decoders::register_decoder, m)?)?;
+    m.add_function(wrap_pyfunction!(chunks::decoders::unregister_decoder, m)?)?;
     m.add_class::<wave::WavFile>()?;
     m.add_class::<detail::WavDetail>()?;
     m.add_class::<detail::RawDetail>()?;
     m.add_class::<Format>()?;
+    m.add_class::<detail::Container>()?;
+    m.add_class::<cue::CuePoint>()?;
+    m.add_class::<cue::PlaylistEntry>()?;
+    m.add_class::<chunks::list::InfoEntry>()?;
+    m.add_class::<chunks::map::ChunkDescriptor>()?;
+    m.add_class::<chunks::bext::BextInfo>()?;
+    m.add_class::<chunks::ixml::IxmlInfo>()?;
+    m.add_class::<chunks::ixml::IxmlSpeed>()?;
+    m.add_class::<chunks::ixml::IxmlTrack>()?;
+    m.add_class::<chunks::smpl::SamplerInfo>()?;
+    m.add_class::<chunks::smpl::SampleLoop>()?;
+    m.add_class::<chunks::smpl::LoopType>()?;
+    m.add_class::<chunks::inst::InstrumentInfo>()?;
+    m.add_class::<chunks::acid::AcidInfo>()?;
+    m.add_class::<chunks::cart::CartInfo>()?;
+    m.add_class::<chunks::cart::CartTimer>()?;
+    m.add_class::<chunks::id3::Id3Tag>()?;
+    m.add_class::<chunks::id3::Id3Comment>()?;
+    m.add_class::<chunks::id3::Id3Picture>()?;
+    m.add_class::<chunks::xmp::XmpInfo>()?;
+    m.add_class::<chunks::xmp::XmpMarker>()?;
+    m.add_class::<chunks::levl::PeakEnvelope>()?;
+    m.add_class::<adm::AdmInfo>()?;
+    m.add_class::<adm::AdmProgramme>()?;
+    m.add_class::<adm::AdmContent>()?;
+    m.add_class::<adm::AdmObject>()?;
+    m.add_class::<adm::AdmPackFormat>()?;
+    m.add_class::<chunks::chna::AdmTrack>()?;
+    m.add_class::<chunks::dbmd::DolbyMetadata>()?;
+    m.add_class::<chunks::dbmd::DolbySegment>()?;
+    m.add_class::<chunks::dbmd::DolbySegmentType>()?;
+    m.add_class::<chunks::dbmd::DolbyDigitalInfo>()?;
+    m.add_class::<chunks::dbmd::DolbyAtmosInfo>()?;
+    m.add_class::<chunks::dbmd::DolbyAtmosTrim>()?;
+    m.add_class::<chunks::dbmd::BinauralRenderMode>()?;
     m.add(
         "WavLoadError",
-        py.get_type_bound::<crate::public::exceptions::WavLoadError>(),
+        py.get_type::<crate::public::exceptions::WavLoadError>(),
     )?;
     Ok(())
 }
@@ -0,0 +1,152 @@
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use roxmltree::{Document, Node};
+
+use crate::chunks::{axml::Axml, chna::AdmTrack, chna::Chna};
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdmInfo {
+    pub programmes: Vec<AdmProgramme>,
+    pub contents: Vec<AdmContent>,
+    pub objects: Vec<AdmObject>,
+    pub pack_formats: Vec<AdmPackFormat>,
+    pub tracks: Vec<AdmTrack>,
+    pub xml: Option<String>,
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdmProgramme {
+    pub id: String,
+    pub name: Option<String>,
+    pub language: Option<String>,
+    pub content_refs: Vec<String>,
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdmContent {
+    pub id: String,
+    pub name: Option<String>,
+    pub language: Option<String>,
+    pub object_refs: Vec<String>,
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdmObject {
+    pub id: String,
+    pub name: Option<String>,
+    pub object_refs: Vec<String>,
+    pub pack_format_refs: Vec<String>,
+    pub track_uid_refs: Vec<String>,
+    pub channels: Vec<usize>,
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdmPackFormat {
+    pub id: String,
+    pub name: Option<String>,
+    pub type_label: Option<String>,
+    pub type_definition: Option<String>,
+    pub channel_format_refs: Vec<String>,
+    pub channels: Vec<usize>,
+}
+
+impl AdmInfo {
+    /// Build the ADM object model from the track list and XML, linking elements to channels via track UIDs
+    pub(crate) fn from_chunks(chna: Option<Chna>, axml: Option<Axml>) -> Option<Self> {
+        if chna.is_none() && axml.is_none() {
+            return None;
+        }
+        let tracks = chna.map(|chna| chna.tracks).unwrap_or_default();
+        let xml = axml.map(|axml| axml.xml);
+
+        let mut adm = AdmInfo {
+            programmes: Vec::new(),
+            contents: Vec::new(),
+            objects: Vec::new(),
+            pack_formats: Vec::new(),
+            tracks,
+            xml: None,
+        };
+
+        // An unreadable XML document still leaves the track list usable
+        if let Some(document) = xml.as_deref().and_then(|xml| Document::parse(xml).ok()) {
+            for node in document.descendants().filter(Node::is_element) {
+                match node.tag_name().name() {
+                    "audioProgramme" => adm.programmes.push(AdmProgramme {
+                        id: attribute(node, "audioProgrammeID"),
+                        name: node.attribute("audioProgrammeName").map(String::from),
+                        language: node.attribute("audioProgrammeLanguage").map(String::from),
+                        content_refs: child_texts(node, "audioContentIDRef"),
+                    }),
+                    "audioContent" => adm.contents.push(AdmContent {
+                        id: attribute(node, "audioContentID"),
+                        name: node.attribute("audioContentName").map(String::from),
+                        language: node.attribute("audioContentLanguage").map(String::from),
+                        object_refs: child_texts(node, "audioObjectIDRef"),
+                    }),
+                    "audioObject" => {
+                        let track_uid_refs = child_texts(node, "audioTrackUIDRef");
+                        adm.objects.push(AdmObject {
+                            id: attribute(node, "audioObjectID"),
+                            name: node.attribute("audioObjectName").map(String::from),
+                            object_refs: child_texts(node, "audioObjectIDRef"),
+                            pack_format_refs: child_texts(node, "audioPackFormatIDRef"),
+                            channels: adm.channels(|track| track_uid_refs.contains(&track.uid)),
+                            track_uid_refs,
+                        })
+                    }
+                    "audioPackFormat" => {
+                        let id = attribute(node, "audioPackFormatID");
+                        adm.pack_formats.push(AdmPackFormat {
+                            name: node.attribute("audioPackFormatName").map(String::from),
+                            type_label: node.attribute("typeLabel").map(String::from),
+                            type_definition: node.attribute("typeDefinition").map(String::from),
+                            channel_format_refs: child_texts(node, "audioChannelFormatIDRef"),
+                            channels: adm.channels(|track| track.pack_format_ref == id),
+                            id,
+                        })
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        adm.xml = xml;
+        Some(adm)
+    }
+
+    /// Zero-based channel indexes of the tracks matching the predicate
+    fn channels(&self, predicate: impl Fn(&AdmTrack) -> bool) -> Vec<usize> {
+        let mut channels = self
+            .tracks
+            .iter()
+            .filter(|track| predicate(track))
+            .map(|track| usize::from(track.track_index) - 1)
+            .collect::<Vec<usize>>();
+        channels.sort_unstable();
+        channels.dedup();
+        channels
+    }
+}
+
+fn attribute(node: Node, name: &str) -> String {
+    node.attribute(name).unwrap_or_default().to_string()
+}
+
+fn child_texts(node: Node, name: &str) -> Vec<String> {
+    node.children()
+        .filter(|child| child.has_tag_name(name))
+        .filter_map(|child| child.text())
+        .map(|text| text.trim().to_string())
+        .collect()
+}
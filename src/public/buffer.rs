@@ -0,0 +1,42 @@
+use std::os::raw::{c_int, c_void};
+
+use bytes::Bytes;
+use pyo3::{exceptions::PyBufferError, ffi, prelude::*, types::PyMemoryView};
+
+/// Read-only view of part of a file, shared with Python through the buffer protocol
+#[pyclass(frozen, module = "waveinfo")]
+pub struct ChunkBuffer {
+    data: Bytes,
+}
+
+#[pymethods]
+impl ChunkBuffer {
+    unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        if flags & ffi::PyBUF_WRITABLE == ffi::PyBUF_WRITABLE {
+            Err(PyBufferError::new_err("Chunk data is read-only"))?
+        }
+        let data = &slf.get().data;
+        // Keeps a reference to this object, and so the underlying Bytes, for the life of the view
+        match ffi::PyBuffer_FillInfo(
+            view,
+            slf.as_ptr(),
+            data.as_ptr() as *mut c_void,
+            data.len() as ffi::Py_ssize_t,
+            1,
+            flags,
+        ) {
+            -1 => Err(PyErr::fetch(slf.py())),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl ChunkBuffer {
+    pub(crate) fn memoryview(py: Python<'_>, data: Bytes) -> PyResult<Bound<'_, PyMemoryView>> {
+        PyMemoryView::from(Bound::new(py, Self { data })?.as_any())
+    }
+}
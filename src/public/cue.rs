@@ -0,0 +1,100 @@
+#[cfg(feature = "python")]
+use pyo3::{prelude::*, types::PyDelta};
+
+use crate::chunks::{cue::Cue, list::AssociatedData, plst::Plst};
+#[cfg(feature = "python")]
+use crate::util::seconds_to_timedelta;
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CuePoint {
+    pub id: u32,
+    pub sample_offset: u32,
+    /// None if the file's sample rate is 0, as there's then no time to give
+    pub time: Option<f64>,
+    pub label: Option<String>,
+    pub note: Option<String>,
+    pub length: Option<u32>,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl CuePoint {
+    #[getter]
+    fn get_time<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyDelta>>> {
+        self.time
+            .map(|time| seconds_to_timedelta(py, time))
+            .transpose()
+    }
+}
+
+impl CuePoint {
+    /// Combine cue points with their associated data (labels, notes and region lengths)
+    pub(crate) fn from_chunks(
+        cue: Cue,
+        associated_data: Vec<AssociatedData>,
+        sample_rate: usize,
+    ) -> Vec<Self> {
+        let mut cue_points = cue
+            .points
+            .into_iter()
+            .map(|point| CuePoint {
+                id: point.id,
+                sample_offset: point.sample_offset,
+                time: (sample_rate != 0).then(|| point.sample_offset as f64 / sample_rate as f64),
+                label: None,
+                note: None,
+                length: None,
+            })
+            .collect::<Vec<Self>>();
+
+        for data in associated_data {
+            let cue_id = match &data {
+                AssociatedData::Label { cue_id, .. }
+                | AssociatedData::Note { cue_id, .. }
+                | AssociatedData::LabelledText { cue_id, .. } => *cue_id,
+            };
+            let Some(cue_point) = cue_points.iter_mut().find(|point| point.id == cue_id) else {
+                continue;
+            };
+            match data {
+                AssociatedData::Label { text, .. } => cue_point.label = Some(text),
+                AssociatedData::Note { text, .. } => cue_point.note = Some(text),
+                AssociatedData::LabelledText { sample_length, .. } => {
+                    cue_point.length = Some(sample_length)
+                }
+            }
+        }
+
+        cue_points
+    }
+}
+
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlaylistEntry {
+    pub cue_id: u32,
+    pub length: u32,
+    pub loops: u32,
+    pub cue: Option<CuePoint>,
+}
+
+impl PlaylistEntry {
+    /// Link playlist segments to their cue points, keeping segments whose cue point doesn't exist
+    pub(crate) fn from_chunk(plst: Plst, cues: &[CuePoint]) -> Vec<Self> {
+        plst.segments
+            .into_iter()
+            .map(|segment| PlaylistEntry {
+                cue_id: segment.cue_id,
+                length: segment.length,
+                loops: segment.loops,
+                cue: cues
+                    .iter()
+                    .find(|cue_point| cue_point.id == segment.cue_id)
+                    .cloned(),
+            })
+            .collect()
+    }
+}
@@ -1,14 +1,21 @@
+#[cfg(feature = "python")]
+use bytes::Bytes;
 use num_enum::TryFromPrimitive;
+#[cfg(feature = "python")]
 use pyo3::{prelude::*, types::PyDelta};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
+#[cfg(feature = "python")]
+use crate::util::seconds_to_timedelta;
 use crate::{formats::Format, util::parse_guid};
 
-use super::wave::WavFile;
+#[cfg(feature = "python")]
+use super::{export, wave::WavFile};
 
-#[pyclass(get_all, module = "waveinfo")]
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WavDetail {
     pub format: Format,
     pub duration: f64,
@@ -18,20 +25,29 @@ pub struct WavDetail {
     pub channel_positions: Vec<SpeakerPosition>,
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl WavDetail {
     #[new]
     fn new(file: super::ConstructorArg) -> PyResult<Self> {
-        let wavfile = WavFile::rs_new(file)?;
-        Ok((&wavfile.raw_details).into())
+        let wavfile = WavFile::from_bytes(Bytes::try_from(file)?)?;
+        Ok(wavfile.detail())
+    }
+
+    /// Every detail as nested dicts, lists and JSON-safe scalars
+    fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        export::to_dict(py, self)
+    }
+
+    /// Every detail as a JSON string
+    #[pyo3(signature = (indent=None))]
+    fn to_json(&self, indent: Option<usize>) -> PyResult<String> {
+        export::to_json(self, indent)
     }
 
     #[getter]
     fn get_duration<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDelta>> {
-        let days = 0;
-        let seconds = self.duration.trunc() as i32;
-        let microseconds = (self.duration.fract() * 1_000_000.0) as i32;
-        PyDelta::new_bound(py, days, seconds, microseconds, true)
+        seconds_to_timedelta(py, self.duration)
     }
 }
 
@@ -70,9 +86,11 @@ impl From<&RawDetail> for WavDetail {
     }
 }
 
-#[pyclass(get_all, module = "waveinfo")]
+#[cfg_attr(feature = "python", pyclass(get_all, module = "waveinfo"))]
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RawDetail {
+    pub container: Container,
     pub format_tag: u16,
     pub channels: usize,
     pub sample_rate: usize,
@@ -80,26 +98,71 @@ pub struct RawDetail {
     pub block_size: usize,
     pub sample_depth: usize,
     pub channel_mask: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::util::optional_guid"))]
     pub subformat: Option<[u8; 16]>,
     pub total_samples: usize,
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl RawDetail {
     #[new]
     fn new(file: super::ConstructorArg) -> PyResult<Self> {
-        let wavfile = WavFile::rs_new(file)?;
+        let wavfile = WavFile::from_bytes(Bytes::try_from(file)?)?;
         Ok(wavfile.raw_details)
     }
 
+    /// Every detail as nested dicts, lists and JSON-safe scalars
+    fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        export::to_dict(py, self)
+    }
+
+    /// Every detail as a JSON string
+    #[pyo3(signature = (indent=None))]
+    fn to_json(&self, indent: Option<usize>) -> PyResult<String> {
+        export::to_json(self, indent)
+    }
+
     #[getter]
     fn get_subformat(&self) -> PyResult<Option<String>> {
-        Ok(self.subformat.map(parse_guid))
+        Ok(self.subformat_guid())
+    }
+}
+
+impl RawDetail {
+    /// The extensible format subformat as a hyphenated GUID string
+    pub fn subformat_guid(&self) -> Option<String> {
+        self.subformat.map(parse_guid)
+    }
+}
+
+#[cfg_attr(feature = "python", pyclass(eq, eq_int, frozen, module = "waveinfo"))]
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Container {
+    RIFF,
+    RF64,
+    BW64,
+}
+
+impl Container {
+    pub(crate) fn from_id(chunk_code: &str) -> Option<Self> {
+        match chunk_code {
+            "RIFF" => Some(Self::RIFF),
+            "RF64" => Some(Self::RF64),
+            "BW64" => Some(Self::BW64),
+            _ => None,
+        }
     }
 }
 
-#[pyclass(eq, eq_int, frozen, get_all, module = "waveinfo")]
+#[cfg_attr(
+    feature = "python",
+    pyclass(eq, eq_int, frozen, get_all, module = "waveinfo")
+)]
 #[derive(PartialEq, Clone, Copy, Debug, TryFromPrimitive, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 #[allow(clippy::upper_case_acronyms, non_camel_case_types)]
 pub enum SpeakerPosition {
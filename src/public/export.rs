@@ -0,0 +1,67 @@
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyDict, PyList},
+    IntoPyObjectExt,
+};
+use serde::Serialize;
+use serde_json::{ser::PrettyFormatter, Serializer, Value};
+
+/// Convert a value into nested dicts, lists and scalars, through its serde representation
+pub(crate) fn to_dict(py: Python<'_>, value: &impl Serialize) -> PyResult<PyObject> {
+    value_to_py(py, &to_value(value)?)
+}
+
+/// Serialize a value to a JSON string, pretty printed with the given indent if there is one
+pub(crate) fn to_json(value: &impl Serialize, indent: Option<usize>) -> PyResult<String> {
+    // Going through a Value sorts object keys, so the output doesn't depend on HashMap ordering
+    let value = to_value(value)?;
+    match indent {
+        None => Ok(value.to_string()),
+        Some(indent) => {
+            let indent = " ".repeat(indent);
+            let mut buffer = Vec::new();
+            let mut serializer = Serializer::with_formatter(
+                &mut buffer,
+                PrettyFormatter::with_indent(indent.as_bytes()),
+            );
+            value
+                .serialize(&mut serializer)
+                .map_err(|err| PyValueError::new_err(err.to_string()))?;
+            Ok(String::from_utf8(buffer).expect("serde_json only writes UTF-8"))
+        }
+    }
+}
+
+fn to_value(value: &impl Serialize) -> PyResult<Value> {
+    serde_json::to_value(value).map_err(|err| PyValueError::new_err(err.to_string()))
+}
+
+fn value_to_py(py: Python<'_>, value: &Value) -> PyResult<PyObject> {
+    Ok(match value {
+        Value::Null => py.None(),
+        Value::Bool(value) => value.into_py_any(py)?,
+        Value::Number(number) => match (number.as_u64(), number.as_i64()) {
+            (Some(value), _) => value.into_py_any(py)?,
+            (None, Some(value)) => value.into_py_any(py)?,
+            (None, None) => number.as_f64().unwrap_or(f64::NAN).into_py_any(py)?,
+        },
+        Value::String(value) => value.into_py_any(py)?,
+        Value::Array(values) => PyList::new(
+            py,
+            values
+                .iter()
+                .map(|value| value_to_py(py, value))
+                .collect::<PyResult<Vec<PyObject>>>()?,
+        )?
+        .into_any()
+        .unbind(),
+        Value::Object(map) => {
+            let dict = PyDict::new(py);
+            for (key, value) in map {
+                dict.set_item(key, value_to_py(py, value)?)?;
+            }
+            dict.into_any().unbind()
+        }
+    })
+}
@@ -1,14 +1,26 @@
+#[cfg(feature = "python")]
 use bytes::Bytes;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
+#[cfg(feature = "python")]
 use std::{fs::read, path::PathBuf};
 
+pub mod adm;
+#[cfg(feature = "python")]
+pub mod buffer;
+pub mod cue;
 pub mod detail;
+#[cfg(feature = "python")]
 pub mod exceptions;
+#[cfg(feature = "python")]
+mod export;
 pub mod wave;
 
 pub use crate::formats::Format;
+#[cfg(feature = "python")]
 use crate::util::read_from_filelike;
 
+#[cfg(feature = "python")]
 #[derive(FromPyObject)]
 enum ConstructorArg<'py> {
     Bytes(Vec<u8>),
@@ -16,6 +28,7 @@ enum ConstructorArg<'py> {
     File(Bound<'py, PyAny>),
 }
 
+#[cfg(feature = "python")]
 impl<'py> TryFrom<ConstructorArg<'py>> for Bytes {
     type Error = PyErr;
 
@@ -1,6 +1,15 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, path::PathBuf};
 
-use detail::SpeakerPosition;
+use bytes::Bytes;
+
+use crate::chunks::{
+    axml::Axml,
+    chna::{AdmTrack, Chna},
+    list::AssociatedData,
+    map::ChunkDescriptor,
+};
+use detail::{Container, SpeakerPosition};
+#[cfg(feature = "python")]
 use pyo3::types::PyDict;
 
 use super::*;
@@ -8,10 +17,11 @@ use super::*;
 #[test]
 fn basic_instantiation() {
     let test_file: PathBuf = ["tests", "assets", "arc_master.wav"].iter().collect();
-    let wav_file = wave::WavFile::rs_new(ConstructorArg::Path(test_file));
+    let wav_file = wave::WavFile::from_path(&test_file);
 
     let expected = wave::WavFile {
         raw_details: detail::RawDetail {
+            container: Container::RIFF,
             format_tag: 0x0001,
             channels: 2,
             sample_rate: 192000,
@@ -22,19 +32,202 @@ fn basic_instantiation() {
             subformat: None,
             total_samples: 38433,
         },
+        dolby: None,
         info: HashMap::<String, String>::new(),
+        info_entries: Vec::new(),
+        filler_chunks: Vec::new(),
+        chunks: vec![
+            ChunkDescriptor {
+                id: "RIFF".to_string(),
+                offset: 0,
+                declared_size: 153768,
+                actual_size: 153768,
+                padding: None,
+                parent: None,
+                depth: 0,
+                truncated: false,
+            },
+            ChunkDescriptor {
+                id: "fmt ".to_string(),
+                offset: 12,
+                declared_size: 16,
+                actual_size: 16,
+                padding: None,
+                parent: Some("WAVE".to_string()),
+                depth: 1,
+                truncated: false,
+            },
+            ChunkDescriptor {
+                id: "data".to_string(),
+                offset: 36,
+                declared_size: 153732,
+                actual_size: 153732,
+                padding: None,
+                parent: Some("WAVE".to_string()),
+                depth: 1,
+                truncated: false,
+            },
+        ],
+        truncated: false,
+        bext: None,
+        ixml: None,
+        cues: Vec::new(),
+        playlist: Vec::new(),
+        sampler: None,
+        instrument: None,
+        acid: None,
+        cart: None,
+        id3: None,
+        xmp: None,
+        peak_envelope: None,
+        adm: None,
+        #[cfg(feature = "python")]
+        decoded: HashMap::new(),
+        custom: Vec::new(),
+        data: Bytes::from(std::fs::read(test_file).unwrap()),
     };
 
     assert_eq!(wav_file.unwrap(), expected);
 }
 
+#[test]
+fn rf64_instantiation() {
+    let file_bytes = [
+        b"RF64\xff\xff\xff\xffWAVE".as_slice(),
+        b"ds64\x1c\x00\x00\x00",
+        &80u64.to_le_bytes(),
+        &8u64.to_le_bytes(),
+        &2u64.to_le_bytes(),
+        &0u32.to_le_bytes(),
+        b"fmt \x10\x00\x00\x00\x01\x00\x02\x00\x44\xac\x00\x00\x10\xb1\x02\x00\x04\x00\x10\x00",
+        b"data\xff\xff\xff\xff\x00\x00\x00\x00\x00\x00\x00\x00",
+    ]
+    .concat();
+    let wav_file = wave::WavFile::from_bytes(file_bytes).unwrap();
+
+    assert_eq!(wav_file.raw_details.container, Container::RF64);
+    assert_eq!(wav_file.raw_details.total_samples, 2);
+}
+
+#[test]
+fn fmt_without_channels() {
+    let file_bytes = [
+        b"RIFF\x2c\x00\x00\x00WAVE".as_slice(),
+        b"fmt \x10\x00\x00\x00\x01\x00\x00\x00\x44\xac\x00\x00\x10\xb1\x02\x00\x04\x00\x10\x00",
+        b"data\x08\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    ]
+    .concat();
+
+    let Err(crate::FatalError::Chunk(crate::ChunkError::ChunkParse(err))) =
+        wave::WavFile::from_bytes(file_bytes)
+    else {
+        panic!("Expected a fmt chunk error");
+    };
+    assert_eq!(err.chunk_code, "fmt ");
+}
+
+#[test]
+fn metadata_after_data() {
+    let file_bytes = [
+        b"RIFF\x4e\x00\x00\x00WAVE".as_slice(),
+        b"fmt \x10\x00\x00\x00\x01\x00\x02\x00\x44\xac\x00\x00\x10\xb1\x02\x00\x04\x00\x10\x00",
+        b"data\x08\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        b"LIST\x1a\x00\x00\x00INFOISFT\x0d\x00\x00\x00Lavf61.1.100\x00\x00",
+    ]
+    .concat();
+    let wav_file = wave::WavFile::from_bytes(file_bytes).unwrap();
+
+    assert_eq!(wav_file.raw_details.total_samples, 2);
+    assert_eq!(
+        wav_file.info,
+        HashMap::from([("Software".to_string(), "Lavf61.1.100".to_string())])
+    );
+}
+
+#[test]
+fn chunk_payloads() {
+    let file_bytes = [
+        b"RIFF\x3e\x00\x00\x00WAVE".as_slice(),
+        b"fmt \x10\x00\x00\x00\x01\x00\x02\x00\x44\xac\x00\x00\x10\xb1\x02\x00\x04\x00\x10\x00",
+        b"abcd\x03\x00\x00\x00xyz\x00",
+        b"abcd\x02\x00\x00\x00uv",
+        b"data\x04\x00\x00\x00\x00\x00\x00\x00",
+    ]
+    .concat();
+    let wav_file = wave::WavFile::from_bytes(file_bytes).unwrap();
+
+    assert_eq!(
+        wav_file.chunk_payloads("abcd").collect::<Vec<Bytes>>(),
+        vec![Bytes::from("xyz"), Bytes::from("uv")]
+    );
+    assert_eq!(wav_file.chunk_payloads("efgh").count(), 0);
+
+    #[cfg(feature = "python")]
+    {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let wav_file = Bound::new(py, wav_file).unwrap();
+            let payload = wav_file.call_method1("chunk_data", ("abcd", true)).unwrap();
+            assert!(payload.is_instance_of::<pyo3::types::PyMemoryView>());
+            assert_eq!(
+                payload
+                    .call_method0("tobytes")
+                    .unwrap()
+                    .extract::<Vec<u8>>()
+                    .unwrap(),
+                b"xyz"
+            );
+            let payloads = wav_file.call_method1("all_chunk_data", ("abcd",)).unwrap();
+            assert_eq!(
+                payloads.extract::<Vec<Vec<u8>>>().unwrap(),
+                vec![b"xyz".to_vec(), b"uv".to_vec()]
+            );
+        })
+    }
+}
+
+#[test]
+fn filler_before_fmt() {
+    let file_bytes = [
+        b"RIFF\x3e\x00\x00\x00WAVE".as_slice(),
+        b"JUNK\x0a\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        b"fmt \x10\x00\x00\x00\x01\x00\x02\x00\x44\xac\x00\x00\x10\xb1\x02\x00\x04\x00\x10\x00",
+        b"data\x08\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    ]
+    .concat();
+    let wav_file = wave::WavFile::from_bytes(file_bytes).unwrap();
+
+    assert_eq!(wav_file.raw_details.total_samples, 2);
+    assert_eq!(wav_file.filler_chunks, vec![("JUNK".to_string(), 10)]);
+}
+
+#[test]
+fn truncated_file() {
+    let file_bytes = [
+        b"RIFF\x00\x01\x00\x00WAVE".as_slice(),
+        b"fmt \x10\x00\x00\x00\x01\x00\x02\x00\x44\xac\x00\x00\x10\xb1\x02\x00\x04\x00\x10\x00",
+        b"data\x80\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    ]
+    .concat();
+    let wav_file = wave::WavFile::from_bytes(file_bytes).unwrap();
+
+    assert_eq!(wav_file.raw_details.total_samples, 2);
+    assert_eq!(wav_file.chunks[0].id, "RIFF");
+    assert_eq!(wav_file.chunks[0].declared_size, 256);
+    assert_eq!(wav_file.chunks[0].actual_size, 44);
+    assert!(wav_file.chunks[0].truncated);
+    assert!(wav_file.chunks[2].truncated);
+    assert!(wav_file.truncated);
+}
+
+#[cfg(feature = "python")]
 #[test]
 fn constructor_args() {
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
-        let locals = PyDict::new_bound(py);
-        py.run_bound(
-            r#"
+        let locals = PyDict::new(py);
+        py.run(
+            cr#"
 from io import BytesIO
 from pathlib import Path
 bytes_arg = b"RIFF"
@@ -68,6 +261,7 @@ file_arg = BytesIO(bytes_arg)"#,
     })
 }
 
+#[cfg(feature = "python")]
 #[test]
 fn detail_duration_getter() {
     let wav_detail = detail::WavDetail {
@@ -81,13 +275,13 @@ fn detail_duration_getter() {
 
     pyo3::prepare_freethreaded_python();
     Python::with_gil(|py| {
-        let locals = PyDict::new_bound(py);
+        let locals = PyDict::new(py);
         locals
             .set_item("detail_obj", Py::new(py, wav_detail).unwrap())
             .unwrap();
 
-        py.run_bound(
-            r#"
+        py.run(
+            cr#"
 from datetime import timedelta
 res = detail_obj.duration == timedelta(seconds=42.6)"#,
             None,
@@ -107,6 +301,7 @@ res = detail_obj.duration == timedelta(seconds=42.6)"#,
 #[test]
 fn detail_converter() {
     let raw = detail::RawDetail {
+        container: Container::RIFF,
         format_tag: 0x0006,
         channels: 2,
         sample_rate: 44100,
@@ -134,6 +329,7 @@ fn detail_converter() {
 #[test]
 fn ext_detail_converter() {
     let raw = detail::RawDetail {
+        container: Container::RIFF,
         format_tag: 0xFFFE,
         channels: 2,
         sample_rate: 192000,
@@ -161,6 +357,150 @@ fn ext_detail_converter() {
     assert_eq!(expected, (&raw).into());
 }
 
+#[test]
+fn format_names() {
+    assert_eq!(Format::IEEE_FLOAT.to_string(), "IEEE_FLOAT");
+    assert_eq!("PCM".parse::<Format>(), Ok(Format::PCM));
+    assert!("pcm".parse::<Format>().is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn detail_serialization() {
+    let raw = detail::RawDetail {
+        container: Container::RIFF,
+        format_tag: 0xFFFE,
+        channels: 2,
+        sample_rate: 192000,
+        data_rate: 384000,
+        block_size: 2,
+        sample_depth: 8,
+        channel_mask: Some(3),
+        subformat: Some([
+            0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xaa, 0x00, 0x38,
+            0x9b, 0x71,
+        ]),
+        total_samples: 1921920,
+    };
+    let raw_json = serde_json::to_value(&raw).unwrap();
+    assert_eq!(raw_json["container"], "RIFF");
+    assert_eq!(
+        raw_json["subformat"],
+        "06000000-0000-1000-8000-00aa00389b71"
+    );
+    assert_eq!(
+        serde_json::from_value::<detail::RawDetail>(raw_json).unwrap(),
+        raw
+    );
+
+    let detail = detail::WavDetail::from(&raw);
+    let detail_json = serde_json::to_value(&detail).unwrap();
+    assert_eq!(detail_json["format"], "ALAW");
+    assert_eq!(
+        detail_json["channel_positions"],
+        serde_json::json!(["FRONT_LEFT", "FRONT_RIGHT"])
+    );
+    assert_eq!(
+        serde_json::from_value::<detail::WavDetail>(detail_json).unwrap(),
+        detail
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn binary_serialization() {
+    let picture = crate::Id3Picture {
+        mime_type: "image/png".to_string(),
+        picture_type: 3,
+        description: "Cover".to_string(),
+        data: vec![0x89, 0x50, 0x4e, 0x47],
+    };
+    let picture_json = serde_json::to_value(&picture).unwrap();
+    assert_eq!(picture_json["data"], "89504e47");
+    assert_eq!(
+        serde_json::from_value::<crate::Id3Picture>(picture_json).unwrap(),
+        picture
+    );
+
+    let segment = crate::DolbySegment {
+        segment_id: 1,
+        segment_type: crate::DolbySegmentType::DOLBY_DIGITAL,
+        data: vec![0x01, 0x00, 0xea],
+    };
+    assert_eq!(serde_json::to_value(&segment).unwrap()["data"], "0100ea");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn mix_level_serialization() {
+    let info = crate::DolbyDigitalInfo {
+        channel_mode: "3/2".to_string(),
+        lfe: true,
+        dialnorm: -24,
+        center_mix_level: Some(-3.0),
+        surround_mix_level: Some(f64::NEG_INFINITY),
+        preferred_downmix: None,
+        lt_rt_center_mix_level: None,
+        lt_rt_surround_mix_level: None,
+        lo_ro_center_mix_level: None,
+        lo_ro_surround_mix_level: None,
+    };
+    let info_json = serde_json::to_value(&info).unwrap();
+    assert_eq!(info_json["center_mix_level"], -3.0);
+    assert_eq!(info_json["surround_mix_level"], "-inf");
+    assert_eq!(info_json["lt_rt_center_mix_level"], serde_json::Value::Null);
+    assert_eq!(
+        serde_json::from_value::<crate::DolbyDigitalInfo>(info_json).unwrap(),
+        info
+    );
+
+    let mut invalid = serde_json::to_value(&info).unwrap();
+    invalid["surround_mix_level"] = "loud".into();
+    assert!(serde_json::from_value::<crate::DolbyDigitalInfo>(invalid).is_err());
+}
+
+#[cfg(feature = "python")]
+#[test]
+fn python_exports() {
+    let test_file: PathBuf = ["tests", "assets", "arc_master.wav"].iter().collect();
+    let wav_file = wave::WavFile::from_path(&test_file).unwrap();
+
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let locals = PyDict::new(py);
+        locals
+            .set_item("wav", Py::new(py, wav_file).unwrap())
+            .unwrap();
+
+        py.run(
+            cr#"
+import json
+exported = wav.to_dict()
+res = (
+    exported["detail"]["format"] == "PCM"
+    and abs(exported["detail"]["duration"] - 38433 / 192000) < 1e-9
+    and exported["raw_details"]["subformat"] is None
+    and exported["chunks"][2]["id"] == "data"
+    and exported["bext"] is None
+    and json.loads(wav.to_json()) == exported
+    and json.loads(wav.to_json(indent=4)) == exported
+    and wav.detail.to_dict() == exported["detail"]
+    and json.loads(wav.raw_details.to_json()) == exported["raw_details"]
+)"#,
+            None,
+            Some(&locals),
+        )
+        .unwrap();
+
+        assert!(locals
+            .get_item("res")
+            .unwrap()
+            .unwrap()
+            .extract::<bool>()
+            .unwrap())
+    })
+}
+
 #[test]
 fn speaker_mask() {
     assert_eq!(
@@ -206,3 +546,394 @@ fn speaker_mask() {
         ]
     );
 }
+
+#[test]
+fn cue_points() {
+    let cue = crate::chunks::cue::Cue {
+        points: vec![
+            crate::chunks::cue::CuePointEntry {
+                id: 1,
+                position: 0,
+                data_chunk_id: "data".to_string(),
+                chunk_start: 0,
+                block_start: 0,
+                sample_offset: 24000,
+            },
+            crate::chunks::cue::CuePointEntry {
+                id: 2,
+                position: 0,
+                data_chunk_id: "data".to_string(),
+                chunk_start: 0,
+                block_start: 0,
+                sample_offset: 96000,
+            },
+        ],
+    };
+    let associated_data = vec![
+        AssociatedData::Label {
+            cue_id: 2,
+            text: "Chorus".to_string(),
+        },
+        AssociatedData::LabelledText {
+            cue_id: 2,
+            sample_length: 48000,
+            purpose: "rgn ".to_string(),
+            text: "".to_string(),
+        },
+        AssociatedData::Note {
+            cue_id: 3,
+            text: "Orphaned".to_string(),
+        },
+    ];
+
+    assert_eq!(
+        cue::CuePoint::from_chunks(cue, associated_data, 48000),
+        vec![
+            cue::CuePoint {
+                id: 1,
+                sample_offset: 24000,
+                time: Some(0.5),
+                label: None,
+                note: None,
+                length: None,
+            },
+            cue::CuePoint {
+                id: 2,
+                sample_offset: 96000,
+                time: Some(2.0),
+                label: Some("Chorus".to_string()),
+                note: None,
+                length: Some(48000),
+            },
+        ]
+    );
+}
+
+#[test]
+fn cue_points_without_sample_rate() {
+    let cue = crate::chunks::cue::Cue {
+        points: vec![crate::chunks::cue::CuePointEntry {
+            id: 1,
+            position: 0,
+            data_chunk_id: "data".to_string(),
+            chunk_start: 0,
+            block_start: 0,
+            sample_offset: 24000,
+        }],
+    };
+
+    let cue_points = cue::CuePoint::from_chunks(cue, Vec::new(), 0);
+    assert_eq!(cue_points[0].time, None);
+
+    #[cfg(feature = "python")]
+    {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let cue_point = Bound::new(py, cue_points[0].clone()).unwrap();
+            assert!(cue_point.getattr("time").unwrap().is_none());
+        });
+    }
+}
+
+#[test]
+fn playlist_entries() {
+    let cue_point = cue::CuePoint {
+        id: 1,
+        sample_offset: 0,
+        time: Some(0.0),
+        label: Some("Start".to_string()),
+        note: None,
+        length: None,
+    };
+    let plst = crate::chunks::plst::Plst {
+        segments: vec![
+            crate::chunks::plst::PlaylistSegment {
+                cue_id: 1,
+                length: 48000,
+                loops: 2,
+            },
+            crate::chunks::plst::PlaylistSegment {
+                cue_id: 7,
+                length: 96000,
+                loops: 1,
+            },
+        ],
+    };
+
+    assert_eq!(
+        cue::PlaylistEntry::from_chunk(plst, std::slice::from_ref(&cue_point)),
+        vec![
+            cue::PlaylistEntry {
+                cue_id: 1,
+                length: 48000,
+                loops: 2,
+                cue: Some(cue_point),
+            },
+            cue::PlaylistEntry {
+                cue_id: 7,
+                length: 96000,
+                loops: 1,
+                cue: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn adm_model() {
+    let track = |track_index: u16, uid: &str, pack_format_ref: &str| AdmTrack {
+        track_index,
+        uid: uid.to_string(),
+        track_format_ref: format!("AT_0001000{}_01", track_index),
+        pack_format_ref: pack_format_ref.to_string(),
+    };
+    let chna = Chna {
+        tracks: vec![
+            track(1, "ATU_00000001", "AP_00010002"),
+            track(2, "ATU_00000002", "AP_00010002"),
+            track(3, "ATU_00000003", "AP_00031001"),
+        ],
+    };
+    let xml = r#"<ebuCoreMain xmlns="urn:ebu:metadata-schema:ebuCore_2014"><coreMetadata><format><audioFormatExtended>
+        <audioProgramme audioProgrammeID="APR_1001" audioProgrammeName="Main" audioProgrammeLanguage="en">
+            <audioContentIDRef>ACO_1001</audioContentIDRef>
+        </audioProgramme>
+        <audioContent audioContentID="ACO_1001" audioContentName="Music">
+            <audioObjectIDRef>AO_1001</audioObjectIDRef>
+            <audioObjectIDRef>AO_1002</audioObjectIDRef>
+        </audioContent>
+        <audioObject audioObjectID="AO_1001" audioObjectName="Bed">
+            <audioPackFormatIDRef>AP_00010002</audioPackFormatIDRef>
+            <audioTrackUIDRef>ATU_00000001</audioTrackUIDRef>
+            <audioTrackUIDRef>ATU_00000002</audioTrackUIDRef>
+        </audioObject>
+        <audioObject audioObjectID="AO_1002" audioObjectName="Voice">
+            <audioPackFormatIDRef>AP_00031001</audioPackFormatIDRef>
+            <audioTrackUIDRef>ATU_00000003</audioTrackUIDRef>
+        </audioObject>
+        <audioPackFormat audioPackFormatID="AP_00031001" audioPackFormatName="Voice" typeLabel="0003" typeDefinition="Objects">
+            <audioChannelFormatIDRef>AC_00031001</audioChannelFormatIDRef>
+        </audioPackFormat>
+    </audioFormatExtended></format></coreMetadata></ebuCoreMain>"#;
+
+    let adm = adm::AdmInfo::from_chunks(
+        Some(chna),
+        Some(Axml {
+            xml: xml.to_string(),
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(
+        adm.programmes,
+        vec![adm::AdmProgramme {
+            id: "APR_1001".to_string(),
+            name: Some("Main".to_string()),
+            language: Some("en".to_string()),
+            content_refs: vec!["ACO_1001".to_string()],
+        }]
+    );
+    assert_eq!(adm.contents[0].object_refs, vec!["AO_1001", "AO_1002"]);
+    assert_eq!(adm.objects.len(), 2);
+    assert_eq!(adm.objects[0].channels, vec![0, 1]);
+    assert_eq!(adm.objects[1].channels, vec![2]);
+    assert_eq!(
+        adm.pack_formats,
+        vec![adm::AdmPackFormat {
+            id: "AP_00031001".to_string(),
+            name: Some("Voice".to_string()),
+            type_label: Some("0003".to_string()),
+            type_definition: Some("Objects".to_string()),
+            channel_format_refs: vec!["AC_00031001".to_string()],
+            channels: vec![2],
+        }]
+    );
+    assert_eq!(adm.tracks.len(), 3);
+    assert_eq!(adm.xml.as_deref(), Some(xml));
+}
+
+#[test]
+fn missing_final_pad_byte() {
+    let file_bytes = [
+        b"RIFF\x3f\x00\x00\x00WAVE".as_slice(),
+        b"fmt \x10\x00\x00\x00\x01\x00\x02\x00\x44\xac\x00\x00\x10\xb1\x02\x00\x04\x00\x10\x00",
+        b"data\x04\x00\x00\x00\x00\x00\x00\x00",
+        b"LIST\x0f\x00\x00\x00INFOISFT\x03\x00\x00\x00abc",
+    ]
+    .concat();
+
+    let wav_file = wave::WavFile::from_bytes(file_bytes).unwrap();
+    assert_eq!(
+        wav_file.info,
+        HashMap::from([("Software".to_string(), "abc".to_string())])
+    );
+}
+
+#[cfg(feature = "python")]
+#[test]
+fn python_decoders() {
+    let file_bytes = [
+        b"RIFF\x48\x00\x00\x00WAVE".as_slice(),
+        b"fmt \x10\x00\x00\x00\x01\x00\x02\x00\x44\xac\x00\x00\x10\xb1\x02\x00\x04\x00\x10\x00",
+        b"qcst\x03\x00\x00\x00abc\x00",
+        b"qcst\x02\x00\x00\x00xy",
+        b"qcsx\x02\x00\x00\x00de",
+        b"data\x04\x00\x00\x00\x00\x00\x00\x00",
+    ]
+    .concat();
+
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let decoder = py.eval(c"lambda data: data.decode().upper()", None, None);
+        crate::chunks::decoders::register_decoder("qcst", decoder.unwrap()).unwrap();
+    });
+    let wav_file = wave::WavFile::from_bytes(file_bytes).unwrap();
+    crate::chunks::decoders::unregister_decoder("qcst");
+
+    Python::with_gil(|py| {
+        let wav_file = Bound::new(py, wav_file).unwrap();
+        let decoded = wav_file
+            .getattr("decoded")
+            .unwrap()
+            .extract::<HashMap<String, Vec<String>>>()
+            .unwrap();
+        assert_eq!(
+            decoded,
+            HashMap::from([(
+                "qcst".to_string(),
+                vec!["ABC".to_string(), "XY".to_string()]
+            )])
+        );
+    })
+}
+
+#[cfg(feature = "python")]
+#[test]
+fn python_decoder_raises() {
+    let file_bytes = [
+        b"RIFF\x34\x00\x00\x00WAVE".as_slice(),
+        b"fmt \x10\x00\x00\x00\x01\x00\x02\x00\x44\xac\x00\x00\x10\xb1\x02\x00\x04\x00\x10\x00",
+        b"qerr\x02\x00\x00\x00ab",
+        b"data\x04\x00\x00\x00\x00\x00\x00\x00",
+    ]
+    .concat();
+
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let decoder = py.eval(c"lambda data: 1 / 0", None, None);
+        crate::chunks::decoders::register_decoder("qerr", decoder.unwrap()).unwrap();
+    });
+    let result = wave::WavFile::from_bytes(file_bytes);
+    crate::chunks::decoders::unregister_decoder("qerr");
+
+    let Err(crate::errors::FatalError::Python(err)) = result else {
+        panic!(
+            "Expected the decoder's exception, got {:?}",
+            result.map(|_| ())
+        );
+    };
+    Python::with_gil(|py| assert!(err.is_instance_of::<pyo3::exceptions::PyZeroDivisionError>(py)));
+}
+
+#[derive(Debug, PartialEq)]
+struct AssetId(u32);
+
+struct AssetIdDecoder;
+
+impl crate::ChunkDecoder for AssetIdDecoder {
+    type Output = AssetId;
+
+    fn id(&self) -> &str {
+        "asid"
+    }
+
+    fn decode(&self, mut chunk: crate::Chunk) -> Result<AssetId, crate::ChunkError> {
+        chunk.validate_type("asid")?;
+        Ok(AssetId(chunk.data_u32("assetId")?))
+    }
+}
+
+#[test]
+fn rust_decoders() {
+    let file_bytes = [
+        b"RIFF\x40\x00\x00\x00WAVE".as_slice(),
+        b"fmt \x10\x00\x00\x00\x01\x00\x02\x00\x44\xac\x00\x00\x10\xb1\x02\x00\x04\x00\x10\x00",
+        b"asid\x04\x00\x00\x00\x2a\x00\x00\x00",
+        b"asid\x04\x00\x00\x00\x07\x00\x00\x00",
+        b"data\x04\x00\x00\x00\x00\x00\x00\x00",
+    ]
+    .concat();
+
+    let undecoded = wave::WavFile::from_bytes(file_bytes.clone()).unwrap();
+    assert_eq!(undecoded.decoded_value::<AssetId>(), None);
+
+    let decoders = crate::ChunkDecoders::new().with(AssetIdDecoder);
+    let wav_file = wave::WavFile::from_bytes_with_decoders(file_bytes, &decoders).unwrap();
+
+    assert_eq!(wav_file.decoded_value::<AssetId>(), Some(&AssetId(42)));
+    assert_eq!(
+        wav_file
+            .decoded_values::<AssetId>()
+            .collect::<Vec<&AssetId>>(),
+        vec![&AssetId(42), &AssetId(7)]
+    );
+    assert_eq!(wav_file.decoded_value::<String>(), None);
+}
+
+#[test]
+fn rust_decoder_error() {
+    let file_bytes = [
+        b"RIFF\x32\x00\x00\x00WAVE".as_slice(),
+        b"fmt \x10\x00\x00\x00\x01\x00\x02\x00\x44\xac\x00\x00\x10\xb1\x02\x00\x04\x00\x10\x00",
+        b"asid\x02\x00\x00\x00\x2a\x00",
+        b"data\x04\x00\x00\x00\x00\x00\x00\x00",
+    ]
+    .concat();
+
+    let decoders = crate::ChunkDecoders::new().with(AssetIdDecoder);
+    let Err(crate::FatalError::Chunk(crate::ChunkError::FieldParse(err))) =
+        wave::WavFile::from_bytes_with_decoders(file_bytes, &decoders)
+    else {
+        panic!("Expected the decoder's error");
+    };
+    assert_eq!(err.chunk_code, "asid");
+    assert_eq!(err.field_name, "assetId");
+}
+
+#[test]
+fn bext_text_encoding() {
+    let bext_body = [b"\x83\x65\x83\x58\x83\x67".as_slice(), &[0; 602 - 6]].concat();
+    let file_bytes = |cset: &[u8]| {
+        [
+            b"RIFF".as_slice(),
+            &(4 + 24 + 8 + 602 + cset.len() as u32 + 12).to_le_bytes(),
+            b"WAVE",
+            b"fmt \x10\x00\x00\x00\x01\x00\x02\x00\x44\xac\x00\x00\x10\xb1\x02\x00\x04\x00\x10\x00",
+            b"bext\x5a\x02\x00\x00",
+            &bext_body,
+            cset,
+            b"data\x04\x00\x00\x00\x00\x00\x00\x00",
+        ]
+        .concat()
+    };
+
+    // A CSET chunk applies to text before it as well as after
+    let cset = b"CSET\x08\x00\x00\x00\xa4\x03\x51\x00\x00\x00\x00\x00";
+    let wav_file = wave::WavFile::from_bytes(file_bytes(cset)).unwrap();
+    assert_eq!(wav_file.bext.unwrap().description, "テスト");
+
+    let wav_file = wave::WavFile::from_bytes_with_encoding(file_bytes(b""), "shift_jis").unwrap();
+    assert_eq!(wav_file.bext.unwrap().description, "テスト");
+}
+
+#[test]
+fn unknown_encoding() {
+    let test_file: PathBuf = ["tests", "assets", "arc_master.wav"].iter().collect();
+    let wav_file =
+        wave::WavFile::from_bytes_with_encoding(std::fs::read(test_file).unwrap(), "not-real");
+
+    assert!(matches!(
+        wav_file,
+        Err(crate::FatalError::UnknownEncoding(label)) if label == "not-real"
+    ));
+}
@@ -1,44 +1,369 @@
-use std::collections::HashMap;
+use std::{any::Any, collections::HashMap, path::Path};
 
 use bytes::Bytes;
-use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::{
+    prelude::*,
+    types::{PyBytes, PyList},
+};
 
 use crate::{
-    chunks::{fact::Fact, Chunk, ChunkType},
-    errors::{FatalError, IncorrectChunkError, MissingChunkError},
+    chunks::{
+        acid::AcidInfo,
+        axml::Axml,
+        bext::BextInfo,
+        cart::{Cart, CartInfo},
+        chna::Chna,
+        cset::Charset,
+        cue::Cue,
+        dbmd::DolbyMetadata,
+        decoders::{ChunkDecoders, DecodedValue},
+        ds64::Ds64,
+        fact::Fact,
+        fmt::Fmt,
+        id3::Id3Tag,
+        inst::InstrumentInfo,
+        ixml::IxmlInfo,
+        levl::PeakEnvelope,
+        list::{AssociatedData, InfoEntry, List},
+        map::{chunk_map, ChunkDescriptor},
+        plst::Plst,
+        smpl::SamplerInfo,
+        xmp::XmpInfo,
+        Chunk, ChunkType,
+    },
+    errors::{ChunkParseError, FatalError, IncorrectChunkError, MissingChunkError},
 };
 
-use super::detail::{RawDetail, WavDetail};
+#[cfg(feature = "python")]
+use crate::chunks::decoders::PyDecoded;
+
+use super::{
+    adm::AdmInfo,
+    cue::{CuePoint, PlaylistEntry},
+    detail::{Container, RawDetail, WavDetail},
+};
+#[cfg(feature = "python")]
+use super::{buffer::ChunkBuffer, export};
 
-#[pyclass(get_all, module = "waveinfo")]
+#[cfg_attr(feature = "python", pyclass(module = "waveinfo"))]
 #[derive(Debug, PartialEq)]
 pub struct WavFile {
-    pub(crate) raw_details: RawDetail,
-    pub(crate) info: HashMap<String, String>,
-    //TODO - add this when we do something with it, otherwise it just takes up memory.
-    // data: Bytes,
+    pub raw_details: RawDetail,
+    pub dolby: Option<DolbyMetadata>,
+    pub info: HashMap<String, String>,
+    pub info_entries: Vec<InfoEntry>,
+    pub filler_chunks: Vec<(String, usize)>,
+    pub chunks: Vec<ChunkDescriptor>,
+    /// Whether any chunk is cut short by the end of the file
+    pub truncated: bool,
+    pub bext: Option<BextInfo>,
+    pub ixml: Option<IxmlInfo>,
+    pub cues: Vec<CuePoint>,
+    pub playlist: Vec<PlaylistEntry>,
+    pub sampler: Option<SamplerInfo>,
+    pub instrument: Option<InstrumentInfo>,
+    pub acid: Option<AcidInfo>,
+    pub cart: Option<CartInfo>,
+    pub id3: Option<Id3Tag>,
+    pub xmp: Option<XmpInfo>,
+    pub peak_envelope: Option<PeakEnvelope>,
+    pub adm: Option<AdmInfo>,
+    #[cfg(feature = "python")]
+    pub(crate) decoded: HashMap<String, Vec<PyDecoded>>,
+    pub(crate) custom: Vec<(String, Box<dyn DecodedValue>)>,
+    pub(crate) data: Bytes,
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl WavFile {
     #[new]
-    fn new(file: super::ConstructorArg) -> PyResult<Self> {
-        Self::rs_new(file)
+    #[pyo3(signature = (file, encoding=None))]
+    fn new(file: super::ConstructorArg, encoding: Option<&str>) -> PyResult<Self> {
+        let data = Bytes::try_from(file)?;
+        Ok(match encoding {
+            Some(label) => Self::from_bytes_with_encoding(data, label),
+            None => Self::from_bytes(data),
+        }?)
     }
 
     #[getter]
-    fn detail(&self) -> WavDetail {
-        WavDetail::from(&self.raw_details)
+    fn get_raw_details(&self) -> RawDetail {
+        self.raw_details.clone()
+    }
+
+    #[getter]
+    fn get_dolby(&self) -> Option<DolbyMetadata> {
+        self.dolby.clone()
+    }
+
+    #[getter]
+    fn get_info(&self) -> HashMap<String, String> {
+        self.info.clone()
+    }
+
+    #[getter]
+    fn get_info_entries(&self) -> Vec<InfoEntry> {
+        self.info_entries.clone()
+    }
+
+    #[getter]
+    fn get_filler_chunks(&self) -> Vec<(String, usize)> {
+        self.filler_chunks.clone()
+    }
+
+    #[getter]
+    fn get_chunks(&self) -> Vec<ChunkDescriptor> {
+        self.chunks.clone()
+    }
+
+    #[getter]
+    fn get_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    #[getter]
+    fn get_bext(&self) -> Option<BextInfo> {
+        self.bext.clone()
+    }
+
+    #[getter]
+    fn get_ixml(&self) -> Option<IxmlInfo> {
+        self.ixml.clone()
+    }
+
+    #[getter]
+    fn get_cues(&self) -> Vec<CuePoint> {
+        self.cues.clone()
+    }
+
+    #[getter]
+    fn get_playlist(&self) -> Vec<PlaylistEntry> {
+        self.playlist.clone()
+    }
+
+    #[getter]
+    fn get_sampler(&self) -> Option<SamplerInfo> {
+        self.sampler.clone()
+    }
+
+    #[getter]
+    fn get_instrument(&self) -> Option<InstrumentInfo> {
+        self.instrument.clone()
+    }
+
+    #[getter]
+    fn get_acid(&self) -> Option<AcidInfo> {
+        self.acid.clone()
+    }
+
+    #[getter]
+    fn get_cart(&self) -> Option<CartInfo> {
+        self.cart.clone()
+    }
+
+    #[getter]
+    fn get_id3(&self) -> Option<Id3Tag> {
+        self.id3.clone()
+    }
+
+    #[getter]
+    fn get_xmp(&self) -> Option<XmpInfo> {
+        self.xmp.clone()
+    }
+
+    #[getter]
+    fn get_peak_envelope(&self) -> Option<PeakEnvelope> {
+        self.peak_envelope.clone()
+    }
+
+    #[getter]
+    fn get_adm(&self) -> Option<AdmInfo> {
+        self.adm.clone()
+    }
+
+    #[getter]
+    fn get_detail(&self) -> WavDetail {
+        self.detail()
+    }
+
+    /// Every parsed detail as nested dicts, lists and JSON-safe scalars. Values from registered
+    /// decoders aren't included, as they may be any Python object.
+    fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        export::to_dict(py, self)
+    }
+
+    /// Every parsed detail as a JSON string
+    #[pyo3(signature = (indent=None))]
+    fn to_json(&self, indent: Option<usize>) -> PyResult<String> {
+        export::to_json(self, indent)
+    }
+
+    #[getter]
+    fn decoded(&self, py: Python<'_>) -> HashMap<String, Vec<PyObject>> {
+        self.decoded
+            .iter()
+            .map(|(id, values)| {
+                let values = values.iter().map(|value| value.0.clone_ref(py)).collect();
+                (id.clone(), values)
+            })
+            .collect()
+    }
+
+    #[pyo3(signature = (id, zero_copy=false))]
+    fn chunk_data(&self, py: Python<'_>, id: &str, zero_copy: bool) -> PyResult<Option<PyObject>> {
+        self.chunk_payloads(id)
+            .next()
+            .map(|payload| payload_to_py(py, payload, zero_copy))
+            .transpose()
+    }
+
+    #[pyo3(signature = (id, zero_copy=false))]
+    fn all_chunk_data<'py>(
+        &self,
+        py: Python<'py>,
+        id: &str,
+        zero_copy: bool,
+    ) -> PyResult<Bound<'py, PyList>> {
+        let payloads = self
+            .chunk_payloads(id)
+            .map(|payload| payload_to_py(py, payload, zero_copy))
+            .collect::<PyResult<Vec<PyObject>>>()?;
+        PyList::new(py, payloads)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for WavFile {
+    /// Parsed metadata, along with the converted audio details. Values from registered decoders
+    /// and the raw file aren't included.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("WavFile", 20)?;
+        state.serialize_field("detail", &self.detail())?;
+        state.serialize_field("raw_details", &self.raw_details)?;
+        state.serialize_field("dolby", &self.dolby)?;
+        state.serialize_field("info", &self.info)?;
+        state.serialize_field("info_entries", &self.info_entries)?;
+        state.serialize_field("filler_chunks", &self.filler_chunks)?;
+        state.serialize_field("chunks", &self.chunks)?;
+        state.serialize_field("truncated", &self.truncated)?;
+        state.serialize_field("bext", &self.bext)?;
+        state.serialize_field("ixml", &self.ixml)?;
+        state.serialize_field("cues", &self.cues)?;
+        state.serialize_field("playlist", &self.playlist)?;
+        state.serialize_field("sampler", &self.sampler)?;
+        state.serialize_field("instrument", &self.instrument)?;
+        state.serialize_field("acid", &self.acid)?;
+        state.serialize_field("cart", &self.cart)?;
+        state.serialize_field("id3", &self.id3)?;
+        state.serialize_field("xmp", &self.xmp)?;
+        state.serialize_field("peak_envelope", &self.peak_envelope)?;
+        state.serialize_field("adm", &self.adm)?;
+        state.end()
     }
 }
 
+#[cfg(feature = "python")]
+fn payload_to_py(py: Python<'_>, payload: Bytes, zero_copy: bool) -> PyResult<PyObject> {
+    Ok(match zero_copy {
+        true => ChunkBuffer::memoryview(py, payload)?.into_any().unbind(),
+        false => PyBytes::new(py, &payload).into_any().unbind(),
+    })
+}
+
 impl WavFile {
-    pub(super) fn rs_new(file: super::ConstructorArg) -> PyResult<Self> {
-        let mut bytes: Bytes = file.try_into()?;
+    /// Parse a wave file held in memory
+    pub fn from_bytes(data: impl Into<Bytes>) -> Result<Self, FatalError> {
+        Self::from_bytes_with_charset(data.into(), Charset::default(), &ChunkDecoders::default())
+    }
+
+    /// Parse a wave file held in memory, decoding chunks this crate doesn't understand with the
+    /// given decoders. An error from a decoder stops the file from loading.
+    pub fn from_bytes_with_decoders(
+        data: impl Into<Bytes>,
+        decoders: &ChunkDecoders,
+    ) -> Result<Self, FatalError> {
+        Self::from_bytes_with_charset(data.into(), Charset::default(), decoders)
+    }
+
+    /// Parse a wave file held in memory, decoding legacy text (INFO, cue labels and notes, bext and
+    /// cart) with the given encoding when the file doesn't declare its own. Encodings are given by
+    /// their WHATWG label. Only [`InfoEntry::encoding`] reports the encoding a string was decoded with.
+    pub fn from_bytes_with_encoding(
+        data: impl Into<Bytes>,
+        encoding: &str,
+    ) -> Result<Self, FatalError> {
+        let charset = Charset::with_fallback(encoding)
+            .ok_or(FatalError::UnknownEncoding(encoding.to_string()))?;
+        Self::from_bytes_with_charset(data.into(), charset, &ChunkDecoders::default())
+    }
+
+    /// Read and parse a wave file from disk
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, FatalError> {
+        Self::from_bytes(std::fs::read(path)?)
+    }
+
+    /// Audio details converted into more useful forms
+    pub fn detail(&self) -> WavDetail {
+        WavDetail::from(&self.raw_details)
+    }
+
+    /// The first value of the given type decoded by a [`crate::ChunkDecoder`]
+    pub fn decoded_value<T: Any>(&self) -> Option<&T> {
+        self.decoded_values().next()
+    }
+
+    /// Every value of the given type decoded by [`crate::ChunkDecoder`]s, in file order
+    pub fn decoded_values<T: Any>(&self) -> impl Iterator<Item = &T> {
+        self.custom
+            .iter()
+            .filter_map(|(_, value)| value.as_ref().as_any().downcast_ref::<T>())
+    }
+
+    /// Payloads of every chunk with the given code, in file order, sharing the file's buffer
+    pub fn chunk_payloads<'a>(&'a self, id: &'a str) -> impl Iterator<Item = Bytes> + 'a {
+        self.chunks
+            .iter()
+            .filter(move |chunk| chunk.id == id)
+            .map(|chunk| {
+                let start = chunk.offset + 8;
+                self.data.slice(start..start + chunk.actual_size)
+            })
+    }
 
-        let mut riff_chunk = Chunk::pop_from_data(&mut bytes).map_err(FatalError::from)?;
+    fn from_bytes_with_charset(
+        data: Bytes,
+        mut charset: Charset,
+        decoders: &ChunkDecoders,
+    ) -> Result<Self, FatalError> {
+        let mut bytes = data.clone();
 
-        riff_chunk.validate_type("RIFF").map_err(FatalError::from)?;
+        // RF64/BW64 files hold their real sizes in a ds64 chunk, which has to be read first
+        let ds64 = match bytes.get(..4) {
+            Some(b"RF64" | b"BW64") => {
+                let mut ds64_data = bytes.slice(bytes.len().min(12)..);
+                let ds64_chunk = Chunk::pop_from_data(&mut ds64_data)
+                    .map_err(|_| FatalError::from(MissingChunkError::new("ds64")))?;
+                Some(Ds64::try_from(ds64_chunk).map_err(FatalError::from)?)
+            }
+            _ => None,
+        };
+
+        // Mapped before anything else, so damaged files can be mapped as far as they go
+        let chunks = chunk_map(&data, ds64.as_ref());
+        let truncated = chunks.iter().any(|chunk| chunk.truncated);
+
+        let mut riff_chunk =
+            Chunk::pop_truncated(&mut bytes, ds64.as_ref()).map_err(FatalError::from)?;
+
+        let container =
+            Container::from_id(&riff_chunk.id).ok_or(FatalError::from(IncorrectChunkError {
+                expected_chunk_code: "RIFF".to_string(),
+                actual_chunk_code: riff_chunk.id.clone(),
+            }))?;
 
         if riff_chunk
             .data_string::<4>("WAVEID")
@@ -48,50 +373,119 @@ impl WavFile {
             Err(riff_chunk.fatal_field_error("WAVEID", "Incorrect RIFF type".to_string()))?
         };
 
-        let mut riff_chunks = riff_chunk.typed_iter();
-
-        let fmt_chunk = riff_chunks
-            .next_ok()
-            .and_then(|chunktype| match chunktype {
-                ChunkType::Fmt(chunk) => Some(chunk),
-                _ => None,
-            })
-            .ok_or(FatalError::from(MissingChunkError::new("fmt")))?;
+        let riff_chunks = riff_chunk
+            .typed_iter()
+            .with_ds64(ds64.clone())
+            .with_decoders(decoders);
 
+        let mut fmt_chunk: Option<Fmt> = None;
         let mut fact_chunk: Option<Fact> = None;
-        let mut info = HashMap::<String, String>::new();
-
-        let data_chunk = loop {
-            match riff_chunks.next() {
-                Some(result) => {
-                    if let Ok(chunktype) = result {
-                        match chunktype {
-                            ChunkType::Data(chunk) => break chunk,
-                            ChunkType::Fmt(_) => Err(FatalError::from(IncorrectChunkError {
-                                expected_chunk_code: "Non-fmt".to_string(),
-                                actual_chunk_code: "fmt".to_string(),
-                            }))?,
-                            ChunkType::Fact(chunk) => fact_chunk = Some(chunk),
-                            ChunkType::List(chunk) => {
-                                let hm: Result<HashMap<String, String>, _> = chunk.try_into();
-                                if let Ok(hm) = hm {
-                                    info = hm;
-                                }
-                            }
-                            //TODO - handle optional metadata chunks that may appear before data chunk
-                            ChunkType::Unknown(_) => (),
-                        }
+        let mut data_chunk: Option<Chunk> = None;
+        let mut lists = Vec::<List>::new();
+        let mut filler_chunks = Vec::<(String, usize)>::new();
+        let mut bext_chunk: Option<Chunk> = None;
+        let mut ixml: Option<IxmlInfo> = None;
+        let mut cue_chunk: Option<Cue> = None;
+        let mut plst_chunk: Option<Plst> = None;
+        let mut sampler: Option<SamplerInfo> = None;
+        let mut instrument: Option<InstrumentInfo> = None;
+        let mut acid: Option<AcidInfo> = None;
+        let mut cart_chunk: Option<Chunk> = None;
+        let mut id3: Option<Id3Tag> = None;
+        let mut xmp: Option<XmpInfo> = None;
+        let mut peak_envelope: Option<PeakEnvelope> = None;
+        let mut chna_chunk: Option<Chna> = None;
+        let mut axml_chunk: Option<Axml> = None;
+        let mut dolby: Option<DolbyMetadata> = None;
+        #[cfg(feature = "python")]
+        let mut decoded = HashMap::<String, Vec<PyDecoded>>::new();
+        let mut custom = Vec::<(String, Box<dyn DecodedValue>)>::new();
+
+        // Metadata chunks may sit on either side of the data chunk, so walk the whole RIFF body
+        for chunktype in riff_chunks.flatten() {
+            match chunktype {
+                ChunkType::Data(chunk) => {
+                    if fmt_chunk.is_none() {
+                        Err(FatalError::from(MissingChunkError::new("fmt")))?
+                    }
+                    if data_chunk.is_none() {
+                        data_chunk = Some(chunk)
                     }
                 }
-                None => Err(FatalError::from(MissingChunkError::new("data")))?,
+                ChunkType::Fmt(chunk) => {
+                    if fmt_chunk.is_some() {
+                        Err(FatalError::from(IncorrectChunkError {
+                            expected_chunk_code: "Non-fmt".to_string(),
+                            actual_chunk_code: "fmt".to_string(),
+                        }))?
+                    }
+                    fmt_chunk = Some(chunk)
+                }
+                ChunkType::Fact(chunk) => fact_chunk = Some(chunk),
+                // Decoded once the whole file is read, as a CSET chunk may come after them
+                ChunkType::List(chunk) => lists.push(chunk),
+                ChunkType::Cue(chunk) => cue_chunk = Some(chunk),
+                ChunkType::Plst(chunk) => plst_chunk = Some(chunk),
+                ChunkType::Smpl(chunk) => sampler = Some(chunk),
+                ChunkType::Inst(chunk) => instrument = Some(chunk),
+                ChunkType::Acid(chunk) => acid = Some(chunk),
+                ChunkType::Cart(chunk) => cart_chunk = Some(chunk),
+                ChunkType::Id3(chunk) => id3 = Some(chunk),
+                ChunkType::Xmp(chunk) => xmp = Some(chunk),
+                ChunkType::Levl(chunk) => peak_envelope = Some(chunk),
+                ChunkType::Chna(chunk) => chna_chunk = Some(chunk),
+                ChunkType::Axml(chunk) => axml_chunk = Some(chunk),
+                ChunkType::Dbmd(chunk) => dolby = Some(chunk),
+                ChunkType::Cset(chunk) => charset = charset.with_cset(&chunk),
+                ChunkType::Bext(chunk) => bext_chunk = Some(chunk),
+                ChunkType::Ixml(chunk) => ixml = Some(chunk),
+                ChunkType::Filler(chunk) => filler_chunks.push((chunk.id, chunk.size)),
+                #[cfg(feature = "python")]
+                ChunkType::Decoded(id, value) => {
+                    // A decoder raising is the caller's own error, so is passed back to them
+                    decoded.entry(id).or_default().push(value.0?);
+                }
+                ChunkType::Custom(id, value) => custom.push((id, value?)),
+                ChunkType::Ds64(_) | ChunkType::Unknown(_) => (),
             }
-        };
+        }
 
-        let sample_length: usize = match fact_chunk {
-            Some(chunk) => chunk.samples.try_into()?,
-            None => {
-                (8 * data_chunk.size)
-                    / (fmt_chunk.bits_per_sample as usize * fmt_chunk.channels as usize)
+        let bext = bext_chunk.and_then(|chunk| BextInfo::from_chunk(chunk, &charset).ok());
+        let cart_chunk = cart_chunk.and_then(|chunk| Cart::from_chunk(chunk, &charset).ok());
+        let (adtl_lists, info_lists): (Vec<List>, Vec<List>) =
+            lists.into_iter().partition(|list| list.list_type == "adtl");
+        let associated_data = adtl_lists
+            .into_iter()
+            .flat_map(|list| list.associated_data(&charset))
+            .flatten()
+            .collect::<Vec<AssociatedData>>();
+        let info_entries = info_lists
+            .into_iter()
+            .flat_map(|list| list.info_entries(&charset))
+            .flatten()
+            .collect::<Vec<InfoEntry>>();
+        let info = info_entries
+            .iter()
+            .filter_map(InfoEntry::labelled_value)
+            .collect::<HashMap<String, String>>();
+
+        let fmt_chunk = fmt_chunk.ok_or(FatalError::from(MissingChunkError::new("fmt")))?;
+        let data_chunk = data_chunk.ok_or(FatalError::from(MissingChunkError::new("data")))?;
+
+        let sample_length: usize = match (fact_chunk, ds64) {
+            (Some(chunk), Some(ds64)) if chunk.samples == u32::MAX => {
+                ds64.sample_count.try_into()?
+            }
+            (Some(chunk), _) => chunk.samples.try_into()?,
+            (None, _) => {
+                let frame_bits = fmt_chunk.bits_per_sample as usize * fmt_chunk.channels as usize;
+                if frame_bits == 0 {
+                    Err(ChunkParseError {
+                        chunk_code: "fmt ".to_string(),
+                        reason: "No channels or bits per sample to count samples by".to_string(),
+                    })?
+                }
+                (8 * data_chunk.size) / frame_bits
             }
         };
 
@@ -101,6 +495,7 @@ impl WavFile {
         };
 
         let raw_details = RawDetail {
+            container,
             format_tag: u16::from_le_bytes(fmt_chunk.format_tag),
             channels: fmt_chunk.channels.into(),
             sample_rate: fmt_chunk.samples_per_sec.try_into()?,
@@ -112,6 +507,47 @@ impl WavFile {
             total_samples: sample_length,
         };
 
-        Ok(WavFile { raw_details, info })
+        let cues = match cue_chunk {
+            Some(cue_chunk) => {
+                CuePoint::from_chunks(cue_chunk, associated_data, raw_details.sample_rate)
+            }
+            None => Vec::new(),
+        };
+
+        let playlist = match plst_chunk {
+            Some(plst_chunk) => PlaylistEntry::from_chunk(plst_chunk, &cues),
+            None => Vec::new(),
+        };
+
+        let cart =
+            cart_chunk.map(|cart_chunk| cart_chunk.with_sample_rate(raw_details.sample_rate));
+
+        let adm = AdmInfo::from_chunks(chna_chunk, axml_chunk);
+
+        Ok(WavFile {
+            raw_details,
+            dolby,
+            info,
+            info_entries,
+            filler_chunks,
+            chunks,
+            truncated,
+            bext,
+            ixml,
+            cues,
+            playlist,
+            sampler,
+            instrument,
+            acid,
+            cart,
+            id3,
+            xmp,
+            peak_envelope,
+            adm,
+            #[cfg(feature = "python")]
+            decoded,
+            custom,
+            data,
+        })
     }
 }
@@ -1,5 +1,7 @@
-use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::{prelude::*, types::PyDelta};
 
+/// Format GUID bytes, in the order they appear in the file, as lowercase hyphenated hex
 pub(crate) fn parse_guid(bytes: [u8; 16]) -> String {
     let chars = bytes
         .iter()
@@ -16,6 +18,113 @@ pub(crate) fn parse_guid(bytes: [u8; 16]) -> String {
     .join("-")
 }
 
+/// Read a GUID string written by `parse_guid` back into its bytes
+#[cfg(feature = "serde")]
+pub(crate) fn guid_from_str(guid: &str) -> Option<[u8; 16]> {
+    let hex = guid.replace('-', "");
+    if hex.len() != 32 || !hex.is_ascii() {
+        return None;
+    }
+
+    let mut bytes = [0u8; 16];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(bytes)
+}
+
+/// Serialize optional GUIDs as the strings given by `parse_guid`
+#[cfg(feature = "serde")]
+pub(crate) mod optional_guid {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        guid: &Option<[u8; 16]>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match guid {
+            Some(bytes) => serializer.serialize_some(&super::parse_guid(*bytes)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<[u8; 16]>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|guid| {
+                super::guid_from_str(&guid)
+                    .ok_or_else(|| D::Error::custom(format!("Invalid GUID: {}", guid)))
+            })
+            .transpose()
+    }
+}
+
+/// Serialize binary payloads as lowercase hex strings rather than arrays of numbers
+#[cfg(feature = "serde")]
+pub(crate) mod hex_bytes {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        let hex = bytes
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>();
+        serializer.serialize_str(&hex)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        if hex.len() % 2 != 0 || !hex.is_ascii() {
+            Err(D::Error::custom(format!("Invalid hex: {}", hex)))?
+        }
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| {
+                u8::from_str_radix(&hex[i..i + 2], 16)
+                    .map_err(|_| D::Error::custom(format!("Invalid hex: {}", hex)))
+            })
+            .collect()
+    }
+}
+
+/// Serialize optional levels in dB, writing silence as the string "-inf", since JSON has no
+/// infinite numbers and would otherwise write it as null
+#[cfg(feature = "serde")]
+pub(crate) mod optional_level {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    const SILENCE: &str = "-inf";
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Level {
+        Db(f64),
+        Silence(String),
+    }
+
+    pub fn serialize<S: Serializer>(level: &Option<f64>, serializer: S) -> Result<S::Ok, S::Error> {
+        match level {
+            Some(level) if *level == f64::NEG_INFINITY => serializer.serialize_some(SILENCE),
+            Some(level) => serializer.serialize_some(level),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<f64>, D::Error> {
+        Option::<Level>::deserialize(deserializer)?
+            .map(|level| match level {
+                Level::Db(level) => Ok(level),
+                Level::Silence(level) if level == SILENCE => Ok(f64::NEG_INFINITY),
+                Level::Silence(level) => Err(D::Error::custom(format!("Invalid level: {}", level))),
+            })
+            .transpose()
+    }
+}
+
+#[cfg(feature = "python")]
 pub(crate) fn read_from_filelike(filelike: Bound<'_, PyAny>) -> PyResult<Vec<u8>> {
     // This should be unnecessary if this ever becomes reality
     // https://github.com/PyO3/pyo3/issues/933
@@ -26,8 +135,16 @@ pub(crate) fn read_from_filelike(filelike: Bound<'_, PyAny>) -> PyResult<Vec<u8>
     Ok(buffer)
 }
 
+#[cfg(feature = "python")]
+pub(crate) fn seconds_to_timedelta(py: Python<'_>, seconds: f64) -> PyResult<Bound<'_, PyDelta>> {
+    let days = 0;
+    let microseconds = (seconds.fract() * 1_000_000.0) as i32;
+    PyDelta::new(py, days, seconds.trunc() as i32, microseconds, true)
+}
+
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "python")]
     use pyo3::{
         exceptions::PyAttributeError,
         types::{PyDict, PyType},
@@ -44,13 +161,39 @@ mod tests {
         )
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn guid_round_trip() {
+        let uuid_bytes = 193453761000446423301720482639943054353u128.to_be_bytes();
+        assert_eq!(guid_from_str(&parse_guid(uuid_bytes)), Some(uuid_bytes));
+        assert_eq!(guid_from_str("9189d6d0-56ec-49d0-b97d"), None);
+        assert_eq!(guid_from_str("9189d6d0-56ec-49d0-b97d-e56c359834zz"), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn hex_bytes_round_trip() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Payload(#[serde(with = "hex_bytes")] Vec<u8>);
+
+        let json = serde_json::to_string(&Payload(vec![0x00, 0xab, 0x7f])).unwrap();
+        assert_eq!(json, "\"00ab7f\"");
+        assert_eq!(
+            serde_json::from_str::<Payload>(&json).unwrap(),
+            Payload(vec![0x00, 0xab, 0x7f])
+        );
+        assert!(serde_json::from_str::<Payload>("\"0ab\"").is_err());
+        assert!(serde_json::from_str::<Payload>("\"zz\"").is_err());
+    }
+
+    #[cfg(feature = "python")]
     #[test]
     fn read_filelike_ok() {
         pyo3::prepare_freethreaded_python();
         Python::with_gil(|py| {
-            let locals = PyDict::new_bound(py);
-            py.run_bound(
-                r#"
+            let locals = PyDict::new(py);
+            py.run(
+                cr#"
 import io
 filelike = io.BytesIO(b'test')"#,
                 None,
@@ -64,17 +207,18 @@ filelike = io.BytesIO(b'test')"#,
         })
     }
 
+    #[cfg(feature = "python")]
     #[test]
     fn read_filelike_bad_object() {
         pyo3::prepare_freethreaded_python();
         Python::with_gil(|py| {
-            let filelike = py.eval_bound("{b'test'}", None, None).unwrap();
+            let filelike = py.eval(c"{b'test'}", None, None).unwrap();
             let result = read_from_filelike(filelike);
             assert!(result.is_err());
             assert!(result
                 .unwrap_err()
-                .get_type_bound(py)
-                .is(&PyType::new_bound::<PyAttributeError>(py)));
+                .get_type(py)
+                .is(&PyType::new::<PyAttributeError>(py)));
         })
     }
 }
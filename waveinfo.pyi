@@ -1,5 +1,6 @@
+from datetime import timedelta
 from os import PathLike
-from typing import BinaryIO, Optional, Union
+from typing import Any, BinaryIO, Callable, Optional, Union
 
 class WavFile:
     """
@@ -7,21 +8,99 @@ class WavFile:
 
     Parameters:
         file: A path to a file to read in, the file itself, or the raw bytes of the file.
+        encoding: Legacy encoding (eg. "windows-1252", "shift_jis") for text that isn't UTF-8 and has no code page set by a CSET chunk. Defaults to "windows-1252".
+            This applies to INFO entries, cue point labels and notes, and bext and cart text. Only `InfoEntry.encoding` reports the encoding each string was decoded with.
 
     Raises:
         WavLoadError: If the file is unable to be parsed
+        ValueError: If the encoding is not recognised
 
     Attributes:
         detail: Details about the wave file
         info: Optional metadata embedded in the file
+        info_entries: Every INFO list entry in file order, including duplicates and unrecognised codes
         raw_details: Details about the file directly extracted from it
+        dolby: Dolby metadata (dbmd chunk), if present
+        filler_chunks: Code and size of each filler (JUNK, PAD, FLLR) chunk in the file
+        chunks: Every chunk in the file, including those inside LIST chunks, in file order
+        truncated: Whether the file ends before the declared size of any chunk
+        bext: Broadcast wave extension metadata, if present
+        ixml: iXML production sound metadata, if present
+        cues: Cue points (markers and regions) in the audio
+        playlist: Playback order of cue points
+        sampler: Sampler (smpl chunk) details, if present
+        instrument: Instrument (inst chunk) details, if present
+        acid: ACID loop details, if present
+        cart: Radio automation (AES46 cart chunk) details, if present
+        id3: ID3v2 tag, if present
+        xmp: XMP metadata, if present
+        peak_envelope: Peak envelope (levl chunk), if present
+        adm: Audio Definition Model metadata (chna and axml/bxml chunks), if present
+        decoded: Results of registered decoders, as a list per chunk code in file order
     """
 
-    def __init__(self, file: Union[str, PathLike, BinaryIO, bytes]) -> None: ...
+    def __init__(
+        self, file: Union[str, PathLike, BinaryIO, bytes], encoding: Optional[str] = None
+    ) -> None: ...
+    def chunk_data(self, id: str, zero_copy: bool = False) -> Optional[Union[bytes, memoryview]]:
+        """
+        Get the payload of the first chunk with a given code, including chunks inside LIST chunks.
+
+        Parameters:
+            id: Four character code of the chunk (eg. "JUNK")
+            zero_copy: Return a read-only memoryview sharing the file's buffer, rather than a copy
+
+        Returns:
+            The chunk payload, or None if there is no such chunk
+        """
+    def all_chunk_data(self, id: str, zero_copy: bool = False) -> list[Union[bytes, memoryview]]:
+        """
+        Get the payloads of every chunk with a given code, including chunks inside LIST chunks.
+
+        Parameters:
+            id: Four character code of the chunks (eg. "JUNK")
+            zero_copy: Return read-only memoryviews sharing the file's buffer, rather than copies
+
+        Returns:
+            The chunk payloads, in file order
+        """
+    def to_dict(self) -> dict[str, Any]:
+        """
+        Get every parsed detail of the file as nested dicts and lists of JSON-safe values.
+
+        Durations and times are in seconds, formats and other enums are given by name, GUIDs are
+        hyphenated hex strings and binary data, such as ID3 pictures, are hex strings.
+        Values from registered decoders (`decoded`) aren't included, as they may be any Python object.
+        """
+    def to_json(self, indent: Optional[int] = None) -> str:
+        """
+        Get every parsed detail of the file as a JSON string, as given by `to_dict`.
+
+        Parameters:
+            indent: Number of spaces to indent nested values by. The output is on one line if not given
+        """
 
     detail: WavDetail
     raw_details: RawDetail
+    dolby: Optional[DolbyMetadata]
     info: dict[str, str]
+    info_entries: list[InfoEntry]
+    filler_chunks: list[tuple[str, int]]
+    chunks: list[ChunkDescriptor]
+    truncated: bool
+    bext: Optional[BextInfo]
+    ixml: Optional[IxmlInfo]
+    cues: list[CuePoint]
+    playlist: list[PlaylistEntry]
+    sampler: Optional[SamplerInfo]
+    instrument: Optional[InstrumentInfo]
+    acid: Optional[AcidInfo]
+    cart: Optional[CartInfo]
+    id3: Optional[Id3Tag]
+    xmp: Optional[XmpInfo]
+    peak_envelope: Optional[PeakEnvelope]
+    adm: Optional[AdmInfo]
+    decoded: dict[str, list[Any]]
 
 class WavDetail:
     """
@@ -40,6 +119,20 @@ class WavDetail:
     """
 
     def __init__(self, file: Union[str, PathLike, BinaryIO, bytes]) -> None: ...
+    def to_dict(self) -> dict[str, Any]:
+        """
+        Get these details as nested dicts and lists of JSON-safe values.
+
+        Durations and times are in seconds, formats and other enums are given by name, and GUIDs are
+        hyphenated hex strings.
+        """
+    def to_json(self, indent: Optional[int] = None) -> str:
+        """
+        Get these details as a JSON string, as given by `to_dict`.
+
+        Parameters:
+            indent: Number of spaces to indent nested values by. The output is on one line if not given
+        """
 
     format: Format
     duration: float
@@ -56,6 +149,7 @@ class RawDetail:
         file: A path to a file to read in, the file itself, or the raw bytes of the file.
 
     Attributes:
+        container: RIFF container variant of the file
         format_tag: Format tag code
         channels: Number of channels
         sample_rate: Sample rate in Hz
@@ -68,7 +162,22 @@ class RawDetail:
     """
 
     def __init__(self, file: Union[str, PathLike, BinaryIO, bytes]) -> None: ...
+    def to_dict(self) -> dict[str, Any]:
+        """
+        Get these details as nested dicts and lists of JSON-safe values.
+
+        Durations and times are in seconds, formats and other enums are given by name, and GUIDs are
+        hyphenated hex strings.
+        """
+    def to_json(self, indent: Optional[int] = None) -> str:
+        """
+        Get these details as a JSON string, as given by `to_dict`.
 
+        Parameters:
+            indent: Number of spaces to indent nested values by. The output is on one line if not given
+        """
+
+    container: Container
     format_tag: int
     channels: int
     sample_rate: int
@@ -79,6 +188,712 @@ class RawDetail:
     subformat: Optional[str]
     total_samples: int
 
+class ChunkDescriptor:
+    """
+    Location and size of a chunk in a file. The first is the RIFF, RF64 or BW64 chunk holding the rest.
+
+    Attributes:
+        id: Four character code of the chunk
+        offset: Byte offset of the chunk header from the start of the file
+        declared_size: Size given in the chunk header. RF64/BW64 placeholder sizes are `0xFFFFFFFF`
+        actual_size: Bytes of chunk data present in the file, which is less than declared if the file is truncated
+        padding: Value of the padding byte after an odd sized chunk, if present
+        parent: Form type (eg. "WAVE") or list type (eg. "INFO") of the chunk holding this chunk, if any
+        depth: Number of chunks this chunk is nested in
+        truncated: Whether the file ends before the declared size of the chunk
+    """
+
+    id: str
+    offset: int
+    declared_size: int
+    actual_size: int
+    padding: Optional[int]
+    parent: Optional[str]
+    depth: int
+    truncated: bool
+
+class InfoEntry:
+    """
+    An entry from an INFO list.
+
+    Attributes:
+        id: Four character code of the entry (eg. "IART")
+        label: Label used for the entry in `WavFile.info`, if the code is recognised
+        value: Value of the entry
+        encoding: Name of the encoding the value was decoded with (eg. "UTF-8", "windows-1252").
+            Cue point labels and notes, and bext and cart text, are decoded the same way but don't report their encoding
+    """
+
+    id: str
+    label: Optional[str]
+    value: str
+    encoding: str
+
+class CuePoint:
+    """
+    A marker or region in the audio, from the cue chunk and its associated data list.
+
+    Attributes:
+        id: Cue point ID
+        sample_offset: Position of the cue point in samples
+        time: Position of the cue point as a time offset. None if the file's sample rate is 0
+        label: Label of the cue point, if set
+        note: Note attached to the cue point, if set
+        length: Length in samples if the cue point marks a region
+    """
+
+    id: int
+    sample_offset: int
+    time: Optional[timedelta]
+    label: Optional[str]
+    note: Optional[str]
+    length: Optional[int]
+
+class PlaylistEntry:
+    """
+    A segment of the playlist (plst chunk).
+
+    Attributes:
+        cue_id: ID of the cue point to play from
+        length: Length of the segment in samples
+        loops: Number of times to play the segment
+        cue: The cue point to play from, or None if no cue point exists with the ID
+    """
+
+    cue_id: int
+    length: int
+    loops: int
+    cue: Optional[CuePoint]
+
+class BextInfo:
+    """
+    Broadcast wave extension (bext chunk) metadata, as defined by [EBU Tech 3285](https://tech.ebu.ch/publications/tech3285).
+
+    Attributes:
+        description: Free text description of the sound sequence
+        originator: Name of the originator/producer
+        originator_reference: Unambiguous reference allocated by the originator
+        origination_date: Date of creation, formatted yyyy:mm:dd
+        origination_time: Time of creation, formatted hh:mm:ss
+        time_reference: Sample count since midnight of the first sample
+        version: Version of the bext chunk
+        umid: Hex representation of the SMPTE UMID, if set
+        loudness_value: Integrated loudness in LUFS, if set
+        loudness_range: Loudness range in LU, if set
+        max_true_peak_level: Maximum true peak level in dBTP, if set
+        max_momentary_loudness: Highest momentary loudness in LUFS, if set
+        max_short_term_loudness: Highest short-term loudness in LUFS, if set
+        coding_history: Coding history of the audio
+    """
+
+    description: str
+    originator: str
+    originator_reference: str
+    origination_date: str
+    origination_time: str
+    time_reference: int
+    version: int
+    umid: Optional[str]
+    loudness_value: Optional[float]
+    loudness_range: Optional[float]
+    max_true_peak_level: Optional[float]
+    max_momentary_loudness: Optional[float]
+    max_short_term_loudness: Optional[float]
+    coding_history: str
+
+class IxmlInfo:
+    """
+    Production sound metadata from an [iXML](http://www.gallery.co.uk/ixml/) chunk.
+
+    Attributes:
+        version: iXML specification version
+        project: Project name
+        scene: Scene name
+        take: Take name
+        tape: Tape or sound roll name
+        circled: Whether the take is circled
+        note: Free text note
+        speed: Speed and timecode details
+        tracks: Details of each track
+        xml: The raw iXML document
+    """
+
+    version: Optional[str]
+    project: Optional[str]
+    scene: Optional[str]
+    take: Optional[str]
+    tape: Optional[str]
+    circled: Optional[bool]
+    note: Optional[str]
+    speed: Optional[IxmlSpeed]
+    tracks: list[IxmlTrack]
+    xml: str
+
+class IxmlSpeed:
+    """
+    Speed and timecode details from an iXML chunk.
+
+    Attributes:
+        master_speed: Frame rate of the production
+        current_speed: Frame rate the audio was recorded at. Differs from master_speed if pulled up/down
+        timecode_rate: Timecode frame rate
+        drop_frame: Whether the timecode is drop frame
+        file_sample_rate: Sample rate of the file in Hz
+        audio_bit_depth: Bit depth of the audio
+        digitizer_sample_rate: Sample rate the audio was digitised at in Hz
+        timestamp_samples_since_midnight: Sample count since midnight of the first sample
+        timestamp_sample_rate: Sample rate the timestamp is counted at in Hz
+        note: Free text note
+    """
+
+    master_speed: Optional[float]
+    current_speed: Optional[float]
+    timecode_rate: Optional[float]
+    drop_frame: Optional[bool]
+    file_sample_rate: Optional[int]
+    audio_bit_depth: Optional[int]
+    digitizer_sample_rate: Optional[int]
+    timestamp_samples_since_midnight: Optional[int]
+    timestamp_sample_rate: Optional[int]
+    note: Optional[str]
+
+class IxmlTrack:
+    """
+    Track details from an iXML chunk.
+
+    Attributes:
+        channel_index: Channel index of the track in the recorder
+        interleave_index: Index of the track in the file
+        name: Track name
+        function: Track function
+    """
+
+    channel_index: Optional[int]
+    interleave_index: Optional[int]
+    name: Optional[str]
+    function: Optional[str]
+
+class SamplerInfo:
+    """
+    Sampler details from a smpl chunk.
+
+    Attributes:
+        manufacturer: MIDI manufacturer code of the intended sampler
+        product: Manufacturer specific product code
+        sample_period: Duration of a sample in nanoseconds
+        midi_unity_note: MIDI note number at which the sample plays at its original pitch
+        midi_pitch_fraction: Fraction of a semitone to tune up from the unity note, as a fraction of 2^32
+        smpte_format: SMPTE frame rate (0, 24, 25, 29 or 30)
+        smpte_offset: SMPTE time of the first sample, formatted hh:mm:ss:ff
+        loops: Sample loops
+    """
+
+    manufacturer: int
+    product: int
+    sample_period: int
+    midi_unity_note: int
+    midi_pitch_fraction: int
+    smpte_format: int
+    smpte_offset: str
+    loops: list[SampleLoop]
+
+class SampleLoop:
+    """
+    A sample loop from a smpl chunk.
+
+    Attributes:
+        id: Loop identifier
+        loop_type: Direction of the loop
+        start: Start of the loop in samples
+        end: End of the loop in samples, inclusive
+        fraction: Fraction of a sample to fine tune the loop at, as a fraction of 2^32
+        play_count: Number of times to play the loop. 0 loops indefinitely
+    """
+
+    id: int
+    loop_type: LoopType
+    start: int
+    end: int
+    fraction: int
+    play_count: int
+
+class InstrumentInfo:
+    """
+    Instrument details from an inst chunk.
+
+    Attributes:
+        unshifted_note: MIDI note number at which the sample plays at its original pitch
+        fine_tune: Pitch shift in cents
+        gain: Gain in dB
+        low_note: Lowest MIDI note number to play the sample at
+        high_note: Highest MIDI note number to play the sample at
+        low_velocity: Lowest MIDI velocity to play the sample at
+        high_velocity: Highest MIDI velocity to play the sample at
+    """
+
+    unshifted_note: int
+    fine_tune: int
+    gain: int
+    low_note: int
+    high_note: int
+    low_velocity: int
+    high_velocity: int
+
+class AcidInfo:
+    """
+    Loop details from an ACID (acid chunk).
+
+    Attributes:
+        one_shot: Whether the file is a one-shot rather than a loop
+        root_note: MIDI note number of the root note, if set
+        stretch: Whether the file can be time stretched
+        disk_based: Whether the file should be streamed from disk
+        beats: Number of beats
+        meter_numerator: Numerator of the time signature
+        meter_denominator: Denominator of the time signature
+        tempo: Tempo in beats per minute
+    """
+
+    one_shot: bool
+    root_note: Optional[int]
+    stretch: bool
+    disk_based: bool
+    beats: int
+    meter_numerator: int
+    meter_denominator: int
+    tempo: float
+
+class CartInfo:
+    """
+    Radio automation details from an [AES46](https://www.aes.org/publications/standards/search.cfm?docID=41) cart chunk.
+
+    Attributes:
+        version: Version of the cart chunk
+        title: Title of the cut
+        artist: Artist of the cut
+        cut_id: Cut number identification
+        client_id: Client identification
+        category: Category of the cut
+        classification: Classification or auxiliary key
+        out_cue: Out cue text
+        start_date: Date the cut becomes valid, formatted yyyy-mm-dd
+        start_time: Time the cut becomes valid, formatted hh:mm:ss
+        end_date: Date the cut expires, formatted yyyy-mm-dd
+        end_time: Time the cut expires, formatted hh:mm:ss
+        producer_app_id: Name of the application that produced the file
+        producer_app_version: Version of the application that produced the file
+        user_def: User defined text
+        level_reference: Sample value of the 0dB reference level
+        timers: Timer post markers
+        url: URL for more information about the cut
+        tag_text: Free form text
+    """
+
+    version: str
+    title: str
+    artist: str
+    cut_id: str
+    client_id: str
+    category: str
+    classification: str
+    out_cue: str
+    start_date: str
+    start_time: str
+    end_date: str
+    end_time: str
+    producer_app_id: str
+    producer_app_version: str
+    user_def: str
+    level_reference: int
+    timers: list[CartTimer]
+    url: str
+    tag_text: str
+
+class CartTimer:
+    """
+    A timer post marker from a cart chunk.
+
+    Attributes:
+        usage: Usage code of the timer (eg. "INTs" for intro start, "SEGs" for segue start)
+        sample_offset: Position of the timer in samples
+        time: Position of the timer as a time offset. None if the file's sample rate is 0
+    """
+
+    usage: str
+    sample_offset: int
+    time: Optional[timedelta]
+
+class Id3Tag:
+    """
+    An [ID3v2.3 or ID3v2.4](https://id3.org/Developer%20Information) tag from an id3 chunk.
+
+    Attributes:
+        version: ID3 version of the tag
+        title: Title (TIT2 frame)
+        artist: Lead artist (TPE1 frame)
+        album: Album (TALB frame)
+        track_number: Track number (TRCK frame)
+        track_total: Total number of tracks (TRCK frame)
+        isrc: International Standard Recording Code (TSRC frame)
+        comments: Comments (COMM frames)
+        pictures: Attached pictures (APIC frames)
+        text_frames: All text frames keyed by frame ID. User defined text frames are keyed as "TXXX:description". Multiple values are separated by "/"
+    """
+
+    version: str
+    title: Optional[str]
+    artist: Optional[str]
+    album: Optional[str]
+    track_number: Optional[int]
+    track_total: Optional[int]
+    isrc: Optional[str]
+    comments: list[Id3Comment]
+    pictures: list[Id3Picture]
+    text_frames: dict[str, str]
+
+class Id3Comment:
+    """
+    A comment from an ID3 tag.
+
+    Attributes:
+        language: ISO-639-2 language code
+        description: Short content description
+        text: The comment text
+    """
+
+    language: str
+    description: str
+    text: str
+
+class Id3Picture:
+    """
+    An attached picture from an ID3 tag.
+
+    Attributes:
+        mime_type: MIME type of the image
+        picture_type: ID3 picture type (eg. 3 for front cover)
+        description: Description of the picture
+        data: The image data
+    """
+
+    mime_type: str
+    picture_type: int
+    description: str
+    data: bytes
+
+class XmpInfo:
+    """
+    [XMP](https://developer.adobe.com/xmp/docs/) metadata from a _PMX chunk.
+
+    Attributes:
+        dublin_core: Dublin Core (dc) properties keyed by name. Array properties have a value per item
+        dynamic_media: Simple dynamic media (xmpDM) properties keyed by name
+        rights: Rights management (xmpRights) properties keyed by name
+        markers: Markers from all dynamic media tracks
+        xml: The raw XMP packet
+    """
+
+    dublin_core: dict[str, list[str]]
+    dynamic_media: dict[str, str]
+    rights: dict[str, str]
+    markers: list[XmpMarker]
+    xml: str
+
+class XmpMarker:
+    """
+    A dynamic media marker from XMP metadata.
+
+    Attributes:
+        name: Name of the marker
+        comment: Comment on the marker
+        marker_type: Type of the marker (eg. "Cue", "Index")
+        start_time: Start of the marker in frames of frame_rate
+        duration: Duration of the marker in frames of frame_rate
+        frame_rate: Frame rate of the marker's track (eg. "f48000")
+    """
+
+    name: Optional[str]
+    comment: Optional[str]
+    marker_type: Optional[str]
+    start_time: Optional[str]
+    duration: Optional[str]
+    frame_rate: Optional[str]
+
+class PeakEnvelope:
+    """
+    Peak envelope from a levl chunk, as defined in [EBU Tech 3285 s3](https://tech.ebu.ch/publications/tech3285s3).
+
+    Attributes:
+        version: Version of the peak envelope format
+        format: Size of each peak value - 1 for 8-bit, 2 for 16-bit
+        points_per_value: 1 if only positive peaks are stored, 2 if positive and negative peaks are stored
+        block_size: Number of audio frames summarised by each peak value
+        channels: Number of channels in the envelope
+        frames: Number of peak frames per channel
+        peak_of_peaks_position: Audio frame holding the highest peak, if known
+        timestamp: Time the envelope was created, formatted "YYYY:MM:DD:hh:mm:ss:uuu"
+        positive_peaks: Positive peak values per channel
+        negative_peaks: Absolute negative peak values per channel. Empty if points_per_value is 1
+    """
+
+    version: int
+    format: int
+    points_per_value: int
+    block_size: int
+    channels: int
+    frames: int
+    peak_of_peaks_position: Optional[int]
+    timestamp: str
+    positive_peaks: list[list[int]]
+    negative_peaks: list[list[int]]
+
+class AdmInfo:
+    """
+    [Audio Definition Model](https://www.itu.int/rec/R-REC-BS.2076) metadata, as carried in BW64 files per ITU-R BS.2088.
+
+    Attributes:
+        programmes: audioProgramme elements
+        contents: audioContent elements
+        objects: audioObject elements
+        pack_formats: audioPackFormat elements defined in the file. Common definitions referenced by ID are not included
+        tracks: Track list from the chna chunk
+        xml: The raw ADM XML, decompressed if it came from a bxml chunk
+    """
+
+    programmes: list[AdmProgramme]
+    contents: list[AdmContent]
+    objects: list[AdmObject]
+    pack_formats: list[AdmPackFormat]
+    tracks: list[AdmTrack]
+    xml: Optional[str]
+
+class AdmProgramme:
+    """
+    An ADM audioProgramme element.
+
+    Attributes:
+        id: audioProgrammeID
+        name: audioProgrammeName
+        language: audioProgrammeLanguage
+        content_refs: IDs of the audioContent elements in the programme
+    """
+
+    id: str
+    name: Optional[str]
+    language: Optional[str]
+    content_refs: list[str]
+
+class AdmContent:
+    """
+    An ADM audioContent element.
+
+    Attributes:
+        id: audioContentID
+        name: audioContentName
+        language: audioContentLanguage
+        object_refs: IDs of the audioObject elements in the content
+    """
+
+    id: str
+    name: Optional[str]
+    language: Optional[str]
+    object_refs: list[str]
+
+class AdmObject:
+    """
+    An ADM audioObject element.
+
+    Attributes:
+        id: audioObjectID
+        name: audioObjectName
+        object_refs: IDs of nested audioObject elements
+        pack_format_refs: IDs of the audioPackFormat elements used by the object
+        track_uid_refs: IDs of the audioTrackUID elements used by the object
+        channels: Zero-based indexes of the file's channels carrying the object, matching `WavDetail.channel_positions`
+    """
+
+    id: str
+    name: Optional[str]
+    object_refs: list[str]
+    pack_format_refs: list[str]
+    track_uid_refs: list[str]
+    channels: list[int]
+
+class AdmPackFormat:
+    """
+    An ADM audioPackFormat element.
+
+    Attributes:
+        id: audioPackFormatID
+        name: audioPackFormatName
+        type_label: typeLabel (eg. "0001")
+        type_definition: typeDefinition (eg. "DirectSpeakers", "Objects")
+        channel_format_refs: IDs of the audioChannelFormat elements in the pack
+        channels: Zero-based indexes of the file's channels using the pack, matching `WavDetail.channel_positions`
+    """
+
+    id: str
+    name: Optional[str]
+    type_label: Optional[str]
+    type_definition: Optional[str]
+    channel_format_refs: list[str]
+    channels: list[int]
+
+class AdmTrack:
+    """
+    A track entry from a chna chunk.
+
+    Attributes:
+        track_index: One-based index of the track within the file's channels
+        uid: audioTrackUID of the track
+        track_format_ref: audioTrackFormatID (or audioChannelFormatID) referenced by the track
+        pack_format_ref: audioPackFormatID referenced by the track
+    """
+
+    track_index: int
+    uid: str
+    track_format_ref: str
+    pack_format_ref: str
+
+class DolbyMetadata:
+    """
+    Dolby metadata from a dbmd chunk.
+
+    Attributes:
+        version: Version of the metadata, formatted "a.b.c.d"
+        segments: Every metadata segment in the chunk, including those not decoded
+        dolby_digital: Program details from the Dolby Digital segment, if present
+        dolby_digital_plus: Program details from the Dolby Digital Plus segment, if present
+        atmos: Details from the Dolby Atmos segments, if present
+    """
+
+    version: str
+    segments: list[DolbySegment]
+    dolby_digital: Optional[DolbyDigitalInfo]
+    dolby_digital_plus: Optional[DolbyDigitalInfo]
+    atmos: Optional[DolbyAtmosInfo]
+
+class DolbySegment:
+    """
+    A metadata segment from a dbmd chunk.
+
+    Attributes:
+        segment_id: Numeric ID of the segment
+        segment_type: Type of the segment
+        data: Raw payload of the segment
+    """
+
+    segment_id: int
+    segment_type: DolbySegmentType
+    data: bytes
+
+class DolbyDigitalInfo:
+    """
+    Program details from a Dolby Digital or Dolby Digital Plus metadata segment.
+    Mix levels are in dB, with `-inf` meaning the channels are left out of the downmix.
+    As JSON has no infinite numbers, exports give `-inf` as the string "-inf".
+
+    Attributes:
+        channel_mode: Audio coding mode as front/rear channel counts (eg. "3/2"), or "1+1" for dual mono
+        lfe: Whether the LFE channel is in use
+        dialnorm: Dialogue level in dBFS
+        center_mix_level: Center downmix level
+        surround_mix_level: Surround downmix level
+        preferred_downmix: Preferred stereo downmix ("Lt/Rt", "Lo/Ro" or "Pro Logic II"). Dolby Digital Plus only
+        lt_rt_center_mix_level: Lt/Rt center downmix level. Dolby Digital Plus only
+        lt_rt_surround_mix_level: Lt/Rt surround downmix level. Dolby Digital Plus only
+        lo_ro_center_mix_level: Lo/Ro center downmix level. Dolby Digital Plus only
+        lo_ro_surround_mix_level: Lo/Ro surround downmix level. Dolby Digital Plus only
+    """
+
+    channel_mode: str
+    lfe: bool
+    dialnorm: int
+    center_mix_level: Optional[float]
+    surround_mix_level: Optional[float]
+    preferred_downmix: Optional[str]
+    lt_rt_center_mix_level: Optional[float]
+    lt_rt_surround_mix_level: Optional[float]
+    lo_ro_center_mix_level: Optional[float]
+    lo_ro_surround_mix_level: Optional[float]
+
+class DolbyAtmosInfo:
+    """
+    Details from the Dolby Atmos and Dolby Atmos supplemental metadata segments.
+
+    Attributes:
+        creation_tool: Name of the tool that created the content
+        creation_tool_version: Version of the creation tool, formatted "major.minor.micro"
+        trims: Trim settings per speaker configuration
+        binaural_render_modes: Headphone render mode of each object
+    """
+
+    creation_tool: Optional[str]
+    creation_tool_version: Optional[str]
+    trims: list[DolbyAtmosTrim]
+    binaural_render_modes: list[BinauralRenderMode]
+
+class DolbyAtmosTrim:
+    """
+    Dolby Atmos trim settings for rendering to one speaker configuration. Trims are in dB.
+
+    Attributes:
+        configuration: Speaker configuration the trims apply to (eg. "5.1.2")
+        auto_trim: Whether automatic trims are used instead of the values given
+        center_trim: Center channel trim
+        surround_trim: Surround channel trim
+        height_trim: Height channel trim
+    """
+
+    configuration: str
+    auto_trim: bool
+    center_trim: float
+    surround_trim: float
+    height_trim: float
+
+class Container:
+    """
+    Enum of RIFF container variants. `RF64` and `BW64` files use 64-bit sizes to allow files larger than 4GB.
+    """
+
+    RIFF = 0
+    RF64 = 1
+    BW64 = 2
+
+class LoopType:
+    """
+    Enum of sample loop directions. Reserved and manufacturer specific loop types are `UNKNOWN`.
+    """
+
+    FORWARD = 0
+    ALTERNATING = 1
+    BACKWARD = 2
+    UNKNOWN = 0xFFFFFFFF
+
+class DolbySegmentType:
+    """
+    Enum of Dolby metadata segment types. Reserved and undocumented segment types are `UNKNOWN`.
+    """
+
+    DOLBY_E = 1
+    DOLBY_DIGITAL = 3
+    DOLBY_DIGITAL_PLUS = 7
+    DOLBY_ATMOS = 9
+    DOLBY_ATMOS_SUPPLEMENTAL = 10
+    UNKNOWN = 0xFF
+
+class BinauralRenderMode:
+    """
+    Enum of Dolby Atmos headphone render modes for an object. Reserved modes are `UNKNOWN`.
+    """
+
+    BYPASS = 0
+    NEAR = 1
+    FAR = 2
+    MID = 3
+    NOT_INDICATED = 4
+    UNKNOWN = 0xFF
+
 class Format:
     """
     Enum of wav file formats (codecs) as defined in [RFC2361](https://datatracker.ietf.org/doc/html/rfc2361) or Microsoft multimedia definitions.
@@ -380,3 +1195,26 @@ class WavLoadError(Exception):
     """
     Exception raised if a file is not able to be read as a valid wav file
     """
+
+def register_decoder(id: str, decoder: Callable[[bytes], Any]) -> None:
+    """
+    Register a decoder for chunks that `waveinfo` doesn't otherwise understand.
+    Files read afterwards pass the payload of each chunk with the code to the decoder,
+    and the result is added to `WavFile.decoded`. An exception raised by the decoder
+    is raised when loading the file.
+
+    Parameters:
+        id: Four character code of the chunk (eg. "qcst")
+        decoder: Callable taking the chunk payload
+
+    Raises:
+        ValueError: If the code isn't four ASCII characters, or the decoder isn't callable
+    """
+
+def unregister_decoder(id: str) -> None:
+    """
+    Remove the decoder registered for a chunk code, if any.
+
+    Parameters:
+        id: Four character code of the chunk
+    """